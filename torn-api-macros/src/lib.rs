@@ -126,7 +126,11 @@ fn impl_api_category(ast: &syn::DeriveInput) -> TokenStream {
                     self.0.decode()
                 }
             },
-            (ApiField::Flattened, Some(_)) => todo!(),
+            (ApiField::Flattened, Some(f)) => quote! {
+                pub fn #name(&self) -> serde_json::Result<#type_name> {
+                    #f(&self.0.value)
+                }
+            },
         },
     );
 
@@ -198,9 +202,7 @@ fn to_static_lt(ty: &mut syn::Type) -> bool {
                 for mut arg in &mut ab.args {
                     match &mut arg {
                         syn::GenericArgument::Type(ty) => {
-                            if to_static_lt(ty) {
-                                res = true;
-                            }
+                            res |= to_static_lt(ty);
                         }
                         syn::GenericArgument::Lifetime(lt) => {
                             lt.ident = syn::Ident::new("static", proc_macro2::Span::call_site());
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{serde::ts_seconds, DateTime, Utc};
 use serde::Deserialize;
 use torn_api_macros::IntoOwned;
@@ -66,7 +68,7 @@ pub struct Territory {
     pub coordinate_y: rust_decimal::Decimal,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum AttackResult {
     Attacked,
     Mugged,
@@ -170,3 +172,242 @@ pub struct AttackFull<'a> {
 
     pub modifiers: RespectModifiers,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviveResult {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Revive<'a> {
+    #[serde(with = "ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    pub result: ReviveResult,
+
+    pub reviver_id: i32,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub reviver_faction: Option<i32>,
+    pub target_id: i32,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub target_faction: Option<i32>,
+
+    pub target_hospital_reason: &'a str,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub target_early_discharge: bool,
+    pub target_last_action: LastAction,
+
+    #[cfg(feature = "decimal")]
+    pub chance: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub chance: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReviveFull<'a> {
+    #[serde(with = "ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    pub result: ReviveResult,
+
+    pub reviver_id: i32,
+    pub reviver_name: &'a str,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub reviver_faction: Option<i32>,
+    #[serde(
+        deserialize_with = "de_util::empty_string_is_none",
+        rename = "reviver_factionname"
+    )]
+    pub reviver_faction_name: Option<&'a str>,
+
+    pub target_id: i32,
+    pub target_name: &'a str,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub target_faction: Option<i32>,
+    #[serde(
+        deserialize_with = "de_util::empty_string_is_none",
+        rename = "target_factionname"
+    )]
+    pub target_faction_name: Option<&'a str>,
+
+    pub target_hospital_reason: &'a str,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub target_early_discharge: bool,
+    pub target_last_action: LastAction,
+
+    #[cfg(feature = "decimal")]
+    pub chance: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub chance: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ReportType {
+    Stats,
+    Money,
+    #[serde(rename = "friendorfoe")]
+    FriendOrFoe,
+    #[serde(rename = "anonymousbounties")]
+    AnonymousBounties,
+    Investment,
+    #[serde(rename = "mostwanted")]
+    MostWanted,
+    References,
+    #[serde(rename = "stockanalysis")]
+    StockAnalysis,
+    #[serde(rename = "truelevel")]
+    TrueLevel,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Report<'a> {
+    pub id: &'a str,
+    pub user_id: i32,
+    /// Stock and investment reports don't have a target.
+    #[serde(default, deserialize_with = "de_util::null_or_zero_is_none")]
+    pub target: Option<i32>,
+    #[serde(rename = "type")]
+    pub report_type: ReportType,
+    /// The content of the report, which differs between the report types.
+    pub report: serde_json::Value,
+    #[serde(with = "ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct RankedWarFaction<'a> {
+    pub name: &'a str,
+    pub score: i32,
+    pub chain: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RankedWarInfo {
+    #[serde(with = "ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(deserialize_with = "de_util::zero_date_is_none")]
+    pub end: Option<DateTime<Utc>>,
+    pub target: i32,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub winner: Option<i32>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct RankedWar<'a> {
+    #[serde(borrow)]
+    pub factions: BTreeMap<i32, RankedWarFaction<'a>>,
+    pub war: RankedWarInfo,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainReportMember {
+    #[serde(rename = "userID")]
+    pub user_id: i32,
+    #[serde(rename = "factionID")]
+    pub faction_id: i32,
+
+    pub attacks: i32,
+    pub leave: i32,
+    pub mug: i32,
+    pub hosp: i32,
+    pub war: i32,
+    pub bonus: i32,
+    pub assist: i32,
+    pub retaliation: i32,
+    pub overseas: i32,
+    pub draw: i32,
+    pub escape: i32,
+    pub loss: i32,
+
+    #[cfg(feature = "decimal")]
+    pub respect: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub avg: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub best: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    #[serde(deserialize_with = "de_util::string_or_f64")]
+    pub respect: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(deserialize_with = "de_util::string_or_f64")]
+    pub avg: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(deserialize_with = "de_util::string_or_f64")]
+    pub best: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainReportBonus {
+    pub attacker: i32,
+    pub defender: i32,
+    pub chain: i32,
+    pub respect: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainReport {
+    #[serde(rename = "factionID")]
+    pub faction_id: i32,
+    pub chain: i32,
+    pub leader: i32,
+    pub targets: i32,
+    pub war: i32,
+    pub members: BTreeMap<i32, ChainReportMember>,
+    #[serde(deserialize_with = "de_util::empty_dict_is_empty_array")]
+    pub bonuses: Vec<ChainReportBonus>,
+
+    #[serde(with = "ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(with = "ts_seconds")]
+    pub end: DateTime<Utc>,
+
+    #[cfg(feature = "decimal")]
+    pub respect: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "besthit")]
+    pub best_hit: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    #[serde(deserialize_with = "de_util::string_or_f64")]
+    pub respect: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "besthit", deserialize_with = "de_util::string_or_f64")]
+    pub best_hit: f64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ItemType {
+    Primary,
+    Secondary,
+    Melee,
+    Temporary,
+    Defensive,
+    Collectible,
+    Medical,
+    Drug,
+    Booster,
+    #[serde(rename = "Energy Drink")]
+    EnergyDrink,
+    Alcohol,
+    Book,
+    Candy,
+    Car,
+    Clothing,
+    Electronic,
+    Enhancer,
+    Flower,
+    Jewelry,
+    Other,
+    Special,
+    #[serde(rename = "Supply Pack")]
+    SupplyPack,
+    Virus,
+}
@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use torn_api_macros::{ApiCategory, IntoOwned};
+
+use crate::de_util::timestamp;
+
+pub use crate::common::{LastAction, Status};
+
+#[derive(Debug, Clone, Copy, ApiCategory)]
+#[api(category = "company")]
+#[non_exhaustive]
+pub enum CompanySelection {
+    #[api(type = "Profile", field = "company")]
+    Profile,
+
+    #[api(type = "BTreeMap<i32, Employee>", field = "company_employees")]
+    Employees,
+
+    #[api(type = "Detailed", field = "company_detailed")]
+    Detailed,
+
+    #[api(type = "HashMap<String, StockItem>", field = "company_stock")]
+    Stock,
+
+    #[api(type = "BTreeMap<String, News>", field = "news")]
+    News,
+
+    #[api(type = "BTreeMap<String, News>", field = "news")]
+    NewsFull,
+
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+
+    #[cfg(feature = "key")]
+    #[api(type = "Vec<crate::key::CompanySelection>", field = "selections")]
+    Lookup,
+}
+
+pub type Selection = CompanySelection;
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct ProfileEmployee<'a> {
+    pub name: &'a str,
+    pub position: &'a str,
+    pub days_in_company: i16,
+    pub last_action: LastAction,
+    #[serde(borrow)]
+    pub status: Status<'a>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Profile<'a> {
+    #[serde(rename = "ID")]
+    pub id: i32,
+    pub company_type: i16,
+    pub rating: i16,
+    pub name: &'a str,
+    pub director: i32,
+    pub employees_hired: i16,
+    pub employees_capacity: i16,
+    pub daily_income: i64,
+    pub daily_customers: i32,
+    pub weekly_income: i64,
+    pub weekly_customers: i32,
+    pub days_old: i32,
+
+    #[serde(borrow)]
+    pub employees: BTreeMap<i32, ProfileEmployee<'a>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Effectiveness {
+    pub working_stats: i16,
+    pub settled_in: i16,
+    pub merits: i16,
+    pub director_education: i16,
+    pub management: i16,
+    pub book: i16,
+    pub addiction: i16,
+    pub inactivity: i16,
+    pub total: i16,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Employee<'a> {
+    pub name: &'a str,
+    pub position: &'a str,
+    pub days_in_company: i16,
+    pub wage: i64,
+    pub manual_labor: i32,
+    pub intelligence: i32,
+    pub endurance: i32,
+    #[serde(default)]
+    pub effectiveness: Effectiveness,
+    pub last_action: LastAction,
+    #[serde(borrow)]
+    pub status: Status<'a>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Upgrades<'a> {
+    pub company_size: i16,
+    pub staffroom_size: &'a str,
+    pub storage_size: &'a str,
+    pub storage_space: i32,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Detailed<'a> {
+    #[serde(rename = "ID")]
+    pub id: i32,
+    pub company_funds: i64,
+    pub company_bank: i64,
+    pub popularity: i16,
+    pub efficiency: i16,
+    pub environment: i16,
+    pub trains_available: i16,
+    pub advertising_budget: i64,
+    pub value: i64,
+
+    #[serde(borrow)]
+    pub upgrades: Upgrades<'a>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockItem {
+    pub cost: i64,
+    pub rrp: i64,
+    pub price: i64,
+    pub in_stock: i32,
+    pub on_order: i32,
+    pub sold_amount: i32,
+    pub sold_worth: i64,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct News<'a> {
+    pub news: &'a str,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{async_test, setup, Client, ClientTrait};
+
+    #[async_test]
+    async fn company() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .company(|b| {
+                b.selections([
+                    Selection::Profile,
+                    Selection::Employees,
+                    Selection::Detailed,
+                    Selection::Stock,
+                    Selection::News,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.profile().unwrap();
+        response.employees().unwrap();
+        response.detailed().unwrap();
+        response.stock().unwrap();
+        response.news().unwrap();
+    }
+
+    #[async_test]
+    async fn company_public() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .company(|b| b.id(1).selections([Selection::Profile]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.profile().unwrap().id, 1);
+    }
+}
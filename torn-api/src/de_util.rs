@@ -43,6 +43,38 @@ where
     }
 }
 
+pub(crate) fn timestamp<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    chrono::serde::ts_seconds::deserialize(deserializer)
+}
+
+pub(crate) fn duration_seconds<'de, D>(deserializer: D) -> Result<chrono::Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let i = i64::deserialize(deserializer)?;
+    chrono::Duration::try_seconds(i)
+        .ok_or_else(|| Error::invalid_value(Unexpected::Signed(i), &"duration in seconds"))
+}
+
+pub(crate) fn zero_duration_is_none<'de, D>(
+    deserializer: D,
+) -> Result<Option<chrono::Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let i = i64::deserialize(deserializer)?;
+    if i == 0 {
+        Ok(None)
+    } else {
+        chrono::Duration::try_seconds(i)
+            .map(Some)
+            .ok_or_else(|| Error::invalid_value(Unexpected::Signed(i), &"duration in seconds"))
+    }
+}
+
 pub(crate) fn int_is_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: Deserializer<'de>,
@@ -150,6 +182,13 @@ where
             write!(formatter, "vec or empty object")
         }
 
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Vec::default())
+        }
+
         fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
         where
             A: serde::de::MapAccess<'de>,
@@ -180,6 +219,60 @@ where
     deserializer.deserialize_any(ArrayVisitor(std::marker::PhantomData))
 }
 
+pub(crate) fn empty_array_is_empty_map<'de, D, K, V>(
+    deserializer: D,
+) -> Result<BTreeMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Ord + Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    struct MapVisitor<K, V>(std::marker::PhantomData<(K, V)>);
+
+    impl<'de, K, V> Visitor<'de> for MapVisitor<K, V>
+    where
+        K: Ord + Deserialize<'de>,
+        V: Deserialize<'de>,
+    {
+        type Value = BTreeMap<K, V>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "map or empty array")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut result = BTreeMap::new();
+            while let Some((key, value)) = map.next_entry()? {
+                result.insert(key, value);
+            }
+
+            Ok(result)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            match seq.size_hint() {
+                Some(0) | None => Ok(BTreeMap::default()),
+                Some(len) => Err(A::Error::invalid_length(len, &"empty array")),
+            }
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(BTreeMap::default())
+        }
+    }
+
+    deserializer.deserialize_any(MapVisitor(std::marker::PhantomData))
+}
+
 pub(crate) fn zero_is_none<'de, D, I>(deserializer: D) -> Result<Option<I>, D::Error>
 where
     D: Deserializer<'de>,
@@ -196,6 +289,73 @@ where
     }
 }
 
+pub(crate) fn comma_separated_list<'de, D, I>(deserializer: D) -> Result<Vec<I>, D::Error>
+where
+    D: Deserializer<'de>,
+    I: std::str::FromStr + TryFrom<u64>,
+{
+    struct ListVisitor<I>(std::marker::PhantomData<I>);
+
+    impl<'de, I> Visitor<'de> for ListVisitor<I>
+    where
+        I: std::str::FromStr + TryFrom<u64>,
+    {
+        type Value = Vec<I>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "comma separated list")
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            v.try_into()
+                .map(|v| vec![v])
+                .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            v.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    s.parse()
+                        .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+                })
+                .collect()
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Vec::default())
+        }
+    }
+
+    deserializer.deserialize_any(ListVisitor(std::marker::PhantomData))
+}
+
+pub(crate) fn null_or_zero_is_none<'de, D, I>(deserializer: D) -> Result<Option<I>, D::Error>
+where
+    D: Deserializer<'de>,
+    I: TryFrom<i64>,
+{
+    let num = Option::<i64>::deserialize(deserializer)?.unwrap_or_default();
+
+    if num == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(num.try_into().map_err(|_| {
+            D::Error::invalid_value(Unexpected::Signed(num), &std::any::type_name::<I>())
+        })?))
+    }
+}
+
 pub(crate) fn null_is_empty_dict<'de, D, K, V>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
 where
     D: Deserializer<'de>,
@@ -205,6 +365,52 @@ where
     Ok(Option::deserialize(deserializer)?.unwrap_or_default())
 }
 
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn string_or_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct DumbVisitor;
+
+    impl<'de> Visitor<'de> for DumbVisitor {
+        type Value = f64;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "float or float as string")
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(v as f64)
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(v as f64)
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(v)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_any(DumbVisitor)
+}
+
 #[cfg(feature = "decimal")]
 pub(crate) fn string_or_decimal<'de, D>(deserializer: D) -> Result<rust_decimal::Decimal, D::Error>
 where
@@ -8,9 +8,17 @@ use serde::{
 
 use torn_api_macros::{ApiCategory, IntoOwned};
 
-use crate::de_util::{self, null_is_empty_dict};
+use crate::de_util::{
+    self, empty_array_is_empty_map, empty_dict_is_empty_array, null_is_empty_dict, timestamp,
+};
+
+pub use crate::common::{
+    Attack, AttackFull, ChainReport, ChainReportBonus, ChainReportMember, LastAction, RankedWar,
+    RankedWarFaction, RankedWarInfo, Report, ReportType, Revive, ReviveFull, ReviveResult, Status,
+    Territory,
+};
 
-pub use crate::common::{Attack, AttackFull, LastAction, Status, Territory};
+pub mod news;
 
 #[derive(Debug, Clone, Copy, ApiCategory)]
 #[api(category = "faction")]
@@ -34,6 +42,192 @@ pub enum FactionSelection {
 
     #[api(type = "Option<Chain>", field = "chain", with = "deserialize_chain")]
     Chain,
+
+    #[api(
+        type = "BTreeMap<i32, Revive>",
+        field = "revives",
+        with = "empty_array_is_empty_map"
+    )]
+    RevivesFull,
+
+    #[api(
+        type = "BTreeMap<i32, ReviveFull>",
+        field = "revives",
+        with = "empty_array_is_empty_map"
+    )]
+    Revives,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "weapons",
+        with = "empty_dict_is_empty_array"
+    )]
+    Weapons,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "armor",
+        with = "empty_dict_is_empty_array"
+    )]
+    Armor,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "temporary",
+        with = "empty_dict_is_empty_array"
+    )]
+    Temporary,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "medical",
+        with = "empty_dict_is_empty_array"
+    )]
+    Medical,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "drugs",
+        with = "empty_dict_is_empty_array"
+    )]
+    Drugs,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "boosters",
+        with = "empty_dict_is_empty_array"
+    )]
+    Boosters,
+
+    #[api(
+        type = "Vec<ArmoryItem>",
+        field = "caches",
+        with = "empty_dict_is_empty_array"
+    )]
+    Caches,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "mainnews",
+        with = "empty_array_is_empty_map"
+    )]
+    MainNews,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "attacknews",
+        with = "empty_array_is_empty_map"
+    )]
+    AttackNews,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "armorynews",
+        with = "empty_array_is_empty_map"
+    )]
+    ArmoryNews,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "fundsnews",
+        with = "empty_array_is_empty_map"
+    )]
+    FundsNews,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "membershipnews",
+        with = "empty_array_is_empty_map"
+    )]
+    MembershipNews,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "territorynews",
+        with = "empty_array_is_empty_map"
+    )]
+    TerritoryNews,
+
+    #[api(
+        type = "BTreeMap<String, NewsEntry>",
+        field = "crimenews",
+        with = "empty_array_is_empty_map"
+    )]
+    CrimeNews,
+
+    #[api(
+        type = "BTreeMap<i32, ChainHistory>",
+        field = "chains",
+        with = "empty_array_is_empty_map"
+    )]
+    Chains,
+
+    /// The report of the faction's latest chain, or of a specific chain when the request is made
+    /// with the chain id in place of the faction id.
+    #[api(type = "ChainReport", field = "chainreport")]
+    ChainReport,
+
+    /// Requires the stat to be set with [crate::ApiRequestBuilder::stat].
+    #[api(
+        type = "BTreeMap<String, BTreeMap<i32, Contributor>>",
+        field = "contributors"
+    )]
+    Contributors,
+
+    #[api(
+        type = "BTreeMap<i32, Donation>",
+        field = "donations",
+        with = "empty_array_is_empty_map"
+    )]
+    Donations,
+
+    #[api(type = "Currency", flatten)]
+    Currency,
+
+    #[api(
+        type = "BTreeMap<String, Position>",
+        field = "positions",
+        with = "empty_array_is_empty_map"
+    )]
+    Positions,
+
+    #[api(type = "BTreeMap<String, i64>", field = "stats")]
+    Stats,
+
+    #[api(
+        type = "BTreeMap<String, UpgradeBranch>",
+        field = "upgrades",
+        with = "decode_upgrades"
+    )]
+    Upgrades,
+
+    #[api(type = "OrganisedCrimes", field = "crimes")]
+    Crimes,
+
+    /// Member ids ordered by their crime experience, highest first.
+    #[api(type = "Vec<i32>", field = "crimeexp")]
+    CrimeExp,
+
+    #[api(
+        type = "BTreeMap<i32, Application>",
+        field = "applications",
+        with = "empty_array_is_empty_map"
+    )]
+    Applications,
+
+    #[api(
+        type = "Vec<Report>",
+        field = "reports",
+        with = "empty_dict_is_empty_array"
+    )]
+    Reports,
+
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+
+    #[cfg(feature = "key")]
+    #[api(type = "Vec<crate::key::FactionSelection>", field = "selections")]
+    Lookup,
 }
 
 pub type Selection = FactionSelection;
@@ -87,6 +281,420 @@ pub struct Basic<'a> {
 
     #[serde(borrow, deserialize_with = "de_util::empty_dict_is_empty_array")]
     pub territory_wars: Vec<FactionTerritoryWar<'a>>,
+
+    #[serde(borrow, default, deserialize_with = "empty_array_is_empty_map")]
+    pub ranked_wars: BTreeMap<i32, RankedWar<'a>>,
+}
+
+impl<'a> Basic<'a> {
+    /// The ranked war the faction is currently matched in, if any.
+    pub fn ranked_war(&self) -> Option<(i32, &RankedWar<'a>)> {
+        self.ranked_wars
+            .iter()
+            .next_back()
+            .map(|(id, war)| (*id, war))
+    }
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct ArmoryItem<'a> {
+    #[serde(rename = "ID")]
+    pub id: i32,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: &'a str,
+    pub quantity: i32,
+
+    #[serde(default)]
+    pub available: i32,
+    #[serde(default)]
+    pub loaned: i32,
+    #[serde(default, deserialize_with = "de_util::comma_separated_list")]
+    pub loaned_to: Vec<i32>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct NewsEntry<'a> {
+    #[serde(rename = "news")]
+    pub text: &'a str,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainHistory {
+    pub chain: i32,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+
+    #[cfg(feature = "decimal")]
+    pub respect: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    #[serde(deserialize_with = "de_util::string_or_f64")]
+    pub respect: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Contributor {
+    pub contributed: i64,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub in_faction: bool,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Donation<'a> {
+    pub name: &'a str,
+    pub money_balance: i64,
+    pub points_balance: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Currency {
+    pub faction_id: i32,
+    pub money: i64,
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Permissions {
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_use_medical_item: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_use_booster_item: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_use_drug_item: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_use_energy_refill: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_use_nerve_refill: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_loan_temporary_item: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_loan_weapon_and_armory: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_retrieve_loaned_armory: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_plan_and_initiate_organised_crime: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_access_faction_api: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_give_item: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_give_money: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_give_points: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_manage_forum: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_manage_applications: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_kick_members: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_adjust_member_balance: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_manage_wars: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_manage_upgrades: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_send_newsletter: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_change_announcement: bool,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub can_change_description: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Position {
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub default: bool,
+    #[serde(flatten)]
+    pub permissions: Permissions,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Upgrade<'a> {
+    #[serde(skip)]
+    pub id: i32,
+    pub name: &'a str,
+    pub level: i16,
+    pub ability: &'a str,
+    #[serde(rename = "basecost")]
+    pub base_cost: i64,
+}
+
+/// The unlocked upgrades of one branch, ordered by level.
+#[derive(Debug, IntoOwned)]
+pub struct UpgradeBranch<'a> {
+    pub order: i16,
+    pub upgrades: Vec<Upgrade<'a>>,
+}
+
+fn decode_upgrades<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, UpgradeBranch<'de>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct BranchUpgrade<'a> {
+        branch: &'a str,
+        #[serde(rename = "branchorder")]
+        branch_order: i16,
+        #[serde(borrow, flatten)]
+        upgrade: Upgrade<'a>,
+    }
+
+    let upgrades: BTreeMap<i32, BranchUpgrade> = de_util::empty_array_is_empty_map(deserializer)?;
+
+    let mut branches: BTreeMap<String, UpgradeBranch> = BTreeMap::new();
+    for (
+        id,
+        BranchUpgrade {
+            branch,
+            branch_order,
+            mut upgrade,
+        },
+    ) in upgrades
+    {
+        upgrade.id = id;
+        branches
+            .entry(branch.to_owned())
+            .or_insert_with(|| UpgradeBranch {
+                order: branch_order,
+                upgrades: Vec::new(),
+            })
+            .upgrades
+            .push(upgrade);
+    }
+
+    for branch in branches.values_mut() {
+        branch.upgrades.sort_unstable_by_key(|u| u.level);
+    }
+
+    Ok(branches)
+}
+
+#[derive(Debug, IntoOwned)]
+pub struct Participant<'a> {
+    pub user_id: i32,
+    /// Only reported while the crime is still pending.
+    pub status: Option<Status<'a>>,
+}
+
+fn deserialize_participants<'de, D>(deserializer: D) -> Result<Vec<Participant<'de>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let list: Vec<BTreeMap<i32, Option<Status<'de>>>> = Vec::deserialize(deserializer)?;
+
+    Ok(list
+        .into_iter()
+        .flatten()
+        .map(|(user_id, status)| Participant { user_id, status })
+        .collect())
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct OrganisedCrime<'a> {
+    pub crime_id: i16,
+    pub crime_name: &'a str,
+    #[serde(borrow, deserialize_with = "deserialize_participants")]
+    pub participants: Vec<Participant<'a>>,
+
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub planned_by: Option<i32>,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub initiated_by: Option<i32>,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub initiated: bool,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub success: bool,
+
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub time_started: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub time_ready: DateTime<Utc>,
+    #[serde(deserialize_with = "de_util::zero_date_is_none")]
+    pub time_completed: Option<DateTime<Utc>>,
+
+    pub money_gain: i64,
+    pub respect_gain: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[non_exhaustive]
+pub enum OrganisedCrime2Status {
+    Recruiting,
+    Planning,
+    Successful,
+    Failure,
+    Expired,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemRequirement {
+    pub id: i32,
+    pub is_reusable: bool,
+    pub is_available: bool,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Slot<'a> {
+    pub position: &'a str,
+    pub user_id: Option<i32>,
+    pub success_chance: Option<i16>,
+    pub item_requirement: Option<ItemRequirement>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RewardItem {
+    pub id: i32,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rewards {
+    pub money: i64,
+    pub respect: i32,
+    #[serde(default)]
+    pub items: Vec<RewardItem>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct OrganisedCrime2<'a> {
+    pub id: i32,
+    pub name: &'a str,
+    pub difficulty: i16,
+    pub status: OrganisedCrime2Status,
+    #[serde(borrow)]
+    pub slots: Vec<Slot<'a>>,
+    pub rewards: Option<Rewards>,
+
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub planning_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub ready_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub expired_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub executed_at: Option<DateTime<Utc>>,
+}
+
+/// Factions that have migrated to organised crimes 2.0 report their crimes as a list of slot
+/// based crimes instead of the old map.
+///
+/// A faction without any crimes is reported as an empty array in both cases, which is decoded as
+/// an empty [OrganisedCrimes::Crimes2] since the two can't be told apart.
+#[derive(Debug)]
+pub enum OrganisedCrimes<'a> {
+    Crimes1(BTreeMap<i32, OrganisedCrime<'a>>),
+    Crimes2(Vec<OrganisedCrime2<'a>>),
+}
+
+impl<'a> OrganisedCrimes<'a> {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Crimes1(crimes) => crimes.is_empty(),
+            Self::Crimes2(crimes) => crimes.is_empty(),
+        }
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for OrganisedCrimes<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CrimesVisitor<'a>(std::marker::PhantomData<&'a ()>);
+
+        impl<'de: 'a, 'a> Visitor<'de> for CrimesVisitor<'a> {
+            type Value = OrganisedCrimes<'a>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("map of organised crimes or list of organised crimes 2.0")
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                Deserialize::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+                    .map(OrganisedCrimes::Crimes1)
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                Deserialize::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+                    .map(OrganisedCrimes::Crimes2)
+            }
+        }
+
+        deserializer.deserialize_any(CrimesVisitor(std::marker::PhantomData))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OrganisedCrimesOwned {
+    Crimes1(BTreeMap<i32, OrganisedCrimeOwned>),
+    Crimes2(Vec<OrganisedCrime2Owned>),
+}
+
+impl<'a> crate::into_owned::IntoOwned for OrganisedCrimes<'a> {
+    type Owned = OrganisedCrimesOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Self::Crimes1(crimes) => OrganisedCrimesOwned::Crimes1(crimes.into_owned()),
+            Self::Crimes2(crimes) => OrganisedCrimesOwned::Crimes2(crimes.into_owned()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ApplicationStatus {
+    Active,
+    Accepted,
+    Declined,
+    Withdrawn,
+    Expired,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplicantStats {
+    pub strength: i64,
+    pub speed: i64,
+    pub dexterity: i64,
+    pub defense: i64,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Application<'a> {
+    #[serde(rename = "userID")]
+    pub user_id: i32,
+    pub name: &'a str,
+    pub level: i16,
+    /// Only present if the applicant chose to share their battle stats.
+    #[serde(default)]
+    pub stats: Option<ApplicantStats>,
+    #[serde(deserialize_with = "de_util::empty_string_is_none")]
+    pub message: Option<&'a str>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub expires: DateTime<Utc>,
+    pub status: ApplicationStatus,
 }
 
 #[derive(Debug)]
@@ -240,6 +848,178 @@ mod tests {
         response.chain().unwrap();
     }
 
+    #[async_test]
+    async fn revives() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| b.selections([Selection::Revives]))
+            .await
+            .unwrap();
+
+        response.revives().unwrap();
+        response.revives_full().unwrap();
+    }
+
+    #[async_test]
+    async fn armory() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| {
+                b.selections([
+                    Selection::Weapons,
+                    Selection::Armor,
+                    Selection::Temporary,
+                    Selection::Medical,
+                    Selection::Drugs,
+                    Selection::Boosters,
+                    Selection::Caches,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.weapons().unwrap();
+        response.armor().unwrap();
+        response.temporary().unwrap();
+        response.medical().unwrap();
+        response.drugs().unwrap();
+        response.boosters().unwrap();
+        response.caches().unwrap();
+    }
+
+    #[async_test]
+    async fn news() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| {
+                b.selections([
+                    Selection::MainNews,
+                    Selection::AttackNews,
+                    Selection::ArmoryNews,
+                    Selection::FundsNews,
+                    Selection::MembershipNews,
+                    Selection::TerritoryNews,
+                    Selection::CrimeNews,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.main_news().unwrap();
+        response.attack_news().unwrap();
+        response.armory_news().unwrap();
+        response.funds_news().unwrap();
+        response.membership_news().unwrap();
+        response.territory_news().unwrap();
+        response.crime_news().unwrap();
+    }
+
+    #[async_test]
+    async fn news_range() {
+        let key = setup();
+
+        let to = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| {
+                b.selections([Selection::FundsNews])
+                    .from(to - chrono::Duration::try_days(7).unwrap())
+                    .to(to)
+            })
+            .await
+            .unwrap();
+
+        for entry in response.funds_news().unwrap().values() {
+            assert!(entry.timestamp <= to);
+        }
+    }
+
+    #[async_test]
+    async fn chains() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key.clone())
+            .faction(|b| b.selections([Selection::Chains, Selection::ChainReport]))
+            .await
+            .unwrap();
+
+        let chains = response.chains().unwrap();
+        response.chain_report().unwrap();
+
+        if let Some(chain_id) = chains.keys().next() {
+            let response = Client::default()
+                .torn_api(key)
+                .faction(|b| b.id(chain_id).selections([Selection::ChainReport]))
+                .await
+                .unwrap();
+
+            response.chain_report().unwrap();
+        }
+    }
+
+    #[async_test]
+    async fn admin() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| {
+                b.selections([
+                    Selection::Contributors,
+                    Selection::Donations,
+                    Selection::Currency,
+                    Selection::Positions,
+                    Selection::Stats,
+                    Selection::Upgrades,
+                ])
+                .stat("gymstrength")
+            })
+            .await
+            .unwrap();
+
+        assert!(response.contributors().unwrap().contains_key("gymstrength"));
+        response.donations().unwrap();
+        response.currency().unwrap();
+        response.positions().unwrap();
+        response.stats().unwrap();
+        response.upgrades().unwrap();
+    }
+
+    #[async_test]
+    async fn crimes() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| b.selections([Selection::Crimes, Selection::CrimeExp]))
+            .await
+            .unwrap();
+
+        response.crimes().unwrap();
+        response.crime_exp().unwrap();
+    }
+
+    #[async_test]
+    async fn applications() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| b.selections([Selection::Applications, Selection::Reports]))
+            .await
+            .unwrap();
+
+        response.applications().unwrap();
+        response.reports().unwrap();
+    }
+
     #[async_test]
     async fn faction_public() {
         let key = setup();
@@ -275,4 +1055,87 @@ mod tests {
         response.territory().unwrap();
         assert!(response.chain().unwrap().is_none());
     }
+
+    #[cfg(feature = "key")]
+    #[async_test]
+    async fn lookup() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .faction(|b| b.selections([Selection::Lookup, Selection::Timestamp]))
+            .await
+            .unwrap();
+
+        assert!(response
+            .lookup()
+            .unwrap()
+            .contains(&crate::key::FactionSelection::Timestamp));
+        response.timestamp().unwrap();
+    }
+
+    #[test]
+    fn organised_crimes() {
+        let value = serde_json::json!([]);
+        let crimes = OrganisedCrimes::deserialize(&value).unwrap();
+        assert!(matches!(&crimes, OrganisedCrimes::Crimes2(list) if list.is_empty()));
+
+        let value = serde_json::json!({
+            "1": {
+                "crime_id": 8,
+                "crime_name": "Political Assassination",
+                "participants": [
+                    {"2": {"description": "Okay", "details": "", "state": "Okay", "color": "green", "until": 0}}
+                ],
+                "planned_by": 2,
+                "initiated_by": 0,
+                "initiated": 0,
+                "success": 0,
+                "time_started": 1700000000,
+                "time_ready": 1700086400,
+                "time_completed": 0,
+                "money_gain": 0,
+                "respect_gain": 0
+            }
+        });
+        let OrganisedCrimes::Crimes1(crimes) = OrganisedCrimes::deserialize(&value).unwrap() else {
+            panic!("expected organised crimes 1.0");
+        };
+        let crime = &crimes[&1];
+        assert_eq!(crime.crime_name, "Political Assassination");
+        assert_eq!(crime.participants[0].user_id, 2);
+        assert_eq!(
+            crime.participants[0].status.as_ref().map(|s| s.state),
+            Some(crate::common::State::Okay)
+        );
+
+        let value = serde_json::json!([{
+            "id": 1,
+            "name": "Mob Mentality",
+            "difficulty": 1,
+            "status": "Recruiting",
+            "slots": [{"position": "Looter #1", "user_id": null, "success_chance": 60, "item_requirement": null}],
+            "rewards": null,
+            "created_at": 1700000000
+        }]);
+        let OrganisedCrimes::Crimes2(crimes) = OrganisedCrimes::deserialize(&value).unwrap() else {
+            panic!("expected organised crimes 2.0");
+        };
+        assert_eq!(crimes[0].slots[0].position, "Looter #1");
+    }
+
+    #[test]
+    fn reports_without_target() {
+        let value = serde_json::json!([
+            {"id": "a", "user_id": 1, "target": null, "type": "stockanalysis", "report": {}, "timestamp": 1700000000},
+            {"id": "b", "user_id": 1, "type": "investment", "report": {}, "timestamp": 1700000000},
+            {"id": "c", "user_id": 1, "target": 0, "type": "money", "report": {}, "timestamp": 1700000000},
+            {"id": "d", "user_id": 1, "target": 2, "type": "stats", "report": {}, "timestamp": 1700000000},
+        ]);
+
+        let reports = Vec::<Report>::deserialize(&value).unwrap();
+
+        let targets: Vec<_> = reports.iter().map(|r| r.target).collect();
+        assert_eq!(targets, [None, None, None, Some(2)]);
+    }
 }
@@ -0,0 +1,446 @@
+use crate::html::{self, Markup};
+
+use super::NewsEntry;
+
+/// The structured content of a [NewsEntry] from the armory, funds or membership news, as
+/// classified by [NewsKind::parse].
+///
+/// Users are only known when the entry links to them, so they are reported as `None` otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NewsKind<'a> {
+    Deposit {
+        user_id: Option<i32>,
+        amount: i64,
+    },
+    Withdrawal {
+        user_id: Option<i32>,
+        amount: i64,
+    },
+    GaveMoney {
+        giver_id: Option<i32>,
+        recipient_id: Option<i32>,
+        amount: i64,
+    },
+    DepositedPoints {
+        user_id: Option<i32>,
+        amount: i64,
+    },
+    GavePoints {
+        giver_id: Option<i32>,
+        recipient_id: Option<i32>,
+        amount: i64,
+    },
+    /// A manual adjustment of a member's balance in the faction vault. Decreases are reported with
+    /// a negative `amount`.
+    BalanceAdjusted {
+        user_id: Option<i32>,
+        adjusted_by: Option<i32>,
+        amount: i64,
+    },
+    DepositedItems {
+        user_id: Option<i32>,
+        item: &'a str,
+        quantity: i32,
+    },
+    GaveItems {
+        giver_id: Option<i32>,
+        recipient_id: Option<i32>,
+        item: &'a str,
+        quantity: i32,
+    },
+    Loaned {
+        giver_id: Option<i32>,
+        recipient_id: Option<i32>,
+        item: &'a str,
+        quantity: i32,
+    },
+    Retrieved {
+        retriever_id: Option<i32>,
+        user_id: Option<i32>,
+        item: &'a str,
+        quantity: i32,
+    },
+    UsedItem {
+        user_id: Option<i32>,
+        item: &'a str,
+    },
+    Joined {
+        user_id: Option<i32>,
+        accepted_by: Option<i32>,
+    },
+    Left {
+        user_id: Option<i32>,
+    },
+    Kicked {
+        user_id: Option<i32>,
+        kicked_by: Option<i32>,
+    },
+    PositionChanged {
+        user_id: Option<i32>,
+        changed_by: Option<i32>,
+        position: Option<&'a str>,
+    },
+    Unknown(&'a str),
+}
+
+/// The user performing the action and the user it is performed on, for both
+/// `{} kicked {}` and `{} was kicked by {}`.
+fn actor_and_target(markup: &Markup) -> (Option<i32>, Option<i32>) {
+    if markup.contains(" by {}") {
+        (markup.user_after(" by {}"), markup.user(0))
+    } else {
+        (markup.user(0), markup.user(1))
+    }
+}
+
+/// The text following `phrase` in the first text segment that contains it, up to the nearest of
+/// `terminators`.
+fn text_after<'a>(markup: &Markup<'a>, phrase: &str, terminators: &[&str]) -> Option<&'a str> {
+    markup.texts().find_map(|text| {
+        let pos = text.to_ascii_lowercase().find(phrase)?;
+        let rest = &text[pos + phrase.len()..];
+        let end = terminators
+            .iter()
+            .filter_map(|t| rest.find(t))
+            .min()
+            .unwrap_or(rest.len());
+        let value = rest[..end].trim().trim_end_matches('.');
+        (!value.is_empty()).then_some(value)
+    })
+}
+
+impl<'a> NewsKind<'a> {
+    pub fn parse(text: &'a str) -> Self {
+        let markup = html::parse(text);
+
+        if markup.contains("one of the faction's") {
+            if let Some(item) = text_after(&markup, "one of the faction's ", &[" item"]) {
+                return Self::UsedItem {
+                    user_id: markup.user(0),
+                    item,
+                };
+            }
+        }
+
+        // membership phrases are anchored to the links around them, since item names are free text
+        if markup.contains_any(&["kicked {}", "was kicked"]) {
+            let (kicked_by, user_id) = actor_and_target(&markup);
+            return Self::Kicked { user_id, kicked_by };
+        }
+
+        if markup.contains_any(&[
+            "position of {}",
+            "promoted {}",
+            "demoted {}",
+            "was promoted",
+            "was demoted",
+        ]) {
+            let (changed_by, user_id) = actor_and_target(&markup);
+            return Self::PositionChanged {
+                user_id,
+                changed_by,
+                position: text_after(&markup, " to ", &[" by", "."]),
+            };
+        }
+
+        if markup.contains("accepted") && markup.contains_any(&["application", "into the faction"])
+        {
+            let (accepted_by, user_id) = actor_and_target(&markup);
+            return Self::Joined {
+                user_id,
+                accepted_by,
+            };
+        }
+
+        if markup.contains("joined") {
+            return Self::Joined {
+                user_id: markup.user(0),
+                accepted_by: None,
+            };
+        }
+
+        if markup.contains("left the faction") {
+            return Self::Left {
+                user_id: markup.user(0),
+            };
+        }
+
+        if markup.contains("money balance") {
+            if let Some(amount) = markup.money() {
+                return Self::BalanceAdjusted {
+                    user_id: markup.user(1),
+                    adjusted_by: markup.user(0),
+                    amount: if markup.contains("decreased") {
+                        -amount
+                    } else {
+                        amount
+                    },
+                };
+            }
+        }
+
+        if markup.contains("deposited") {
+            if let Some((quantity, item)) = markup.items() {
+                return Self::DepositedItems {
+                    user_id: markup.user(0),
+                    item,
+                    quantity,
+                };
+            }
+            if let Some(amount) = markup.points() {
+                return Self::DepositedPoints {
+                    user_id: markup.user(0),
+                    amount,
+                };
+            }
+            if let Some(amount) = markup.money() {
+                return Self::Deposit {
+                    user_id: markup.user(0),
+                    amount,
+                };
+            }
+        }
+
+        if markup.contains_any(&["withdrew", "withdrawn"]) {
+            if let Some(amount) = markup.money() {
+                return Self::Withdrawal {
+                    user_id: markup.user(0),
+                    amount,
+                };
+            }
+        }
+
+        if markup.contains("loaned") {
+            if let Some((quantity, item)) = markup.items() {
+                let giver_id = markup.user(0);
+                let recipient_id = if markup.contains("themselves") {
+                    giver_id
+                } else {
+                    markup.user(1)
+                };
+                return Self::Loaned {
+                    giver_id,
+                    recipient_id,
+                    item,
+                    quantity,
+                };
+            }
+        }
+
+        if markup.contains("retrieved") {
+            if let Some((quantity, item)) = markup.items() {
+                return Self::Retrieved {
+                    retriever_id: markup.user(0),
+                    user_id: markup.user(1),
+                    item,
+                    quantity,
+                };
+            }
+        }
+
+        if markup.contains_any(&["gave", "given"]) {
+            let (giver_id, recipient_id) = actor_and_target(&markup);
+            if let Some((quantity, item)) = markup.items() {
+                return Self::GaveItems {
+                    giver_id,
+                    recipient_id,
+                    item,
+                    quantity,
+                };
+            }
+            if let Some(amount) = markup.points() {
+                return Self::GavePoints {
+                    giver_id,
+                    recipient_id,
+                    amount,
+                };
+            }
+            if let Some(amount) = markup.money() {
+                return Self::GaveMoney {
+                    giver_id,
+                    recipient_id,
+                    amount,
+                };
+            }
+        }
+
+        Self::Unknown(text)
+    }
+}
+
+impl<'a> NewsEntry<'a> {
+    pub fn kind(&self) -> NewsKind<'a> {
+        NewsKind::parse(self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn funds() {
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> deposited \
+                 $5,000,000"
+            ),
+            NewsKind::Deposit {
+                user_id: Some(1),
+                amount: 5_000_000,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a> was given \
+                 $1,000 by <a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a>"
+            ),
+            NewsKind::GaveMoney {
+                giver_id: Some(1),
+                recipient_id: Some(2),
+                amount: 1000,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> deposited \
+                 1,000 points"
+            ),
+            NewsKind::DepositedPoints {
+                user_id: Some(1),
+                amount: 1000,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a> was given 100 \
+                 points by <a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a>"
+            ),
+            NewsKind::GavePoints {
+                giver_id: Some(1),
+                recipient_id: Some(2),
+                amount: 100,
+            }
+        );
+    }
+
+    #[test]
+    fn balance_adjustments() {
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> increased \
+                 <a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a>'s money \
+                 balance by $1,000,000 from $0 to $1,000,000"
+            ),
+            NewsKind::BalanceAdjusted {
+                user_id: Some(2),
+                adjusted_by: Some(1),
+                amount: 1_000_000,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> decreased \
+                 <a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a>'s money \
+                 balance by $250,000 from $1,000,000 to $750,000"
+            ),
+            NewsKind::BalanceAdjusted {
+                user_id: Some(2),
+                adjusted_by: Some(1),
+                amount: -250_000,
+            }
+        );
+    }
+
+    #[test]
+    fn armory() {
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> gave 10x \
+                 Xanax to <a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a>"
+            ),
+            NewsKind::GaveItems {
+                giver_id: Some(1),
+                recipient_id: Some(2),
+                item: "Xanax",
+                quantity: 10,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> gave 1x \
+                 Kicked Box to <a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a>"
+            ),
+            NewsKind::GaveItems {
+                giver_id: Some(1),
+                recipient_id: Some(2),
+                item: "Kicked Box",
+                quantity: 1,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> loaned 1x \
+                 Kevlar Vest to themselves from the faction armory"
+            ),
+            NewsKind::Loaned {
+                giver_id: Some(1),
+                recipient_id: Some(1),
+                item: "Kevlar Vest",
+                quantity: 1,
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> used one of \
+                 the faction's Blood Bag : O+ items"
+            ),
+            NewsKind::UsedItem {
+                user_id: Some(1),
+                item: "Blood Bag : O+",
+            }
+        );
+    }
+
+    #[test]
+    fn membership() {
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a> was kicked \
+                 from the faction by <a href = \
+                 \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a>"
+            ),
+            NewsKind::Kicked {
+                user_id: Some(2),
+                kicked_by: Some(1),
+            }
+        );
+
+        assert_eq!(
+            NewsKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=1\">Chedburn</a> changed the \
+                 position of <a href = \"http://www.torn.com/profiles.php?XID=2\">Member</a> \
+                 from Recruit to Lieutenant."
+            ),
+            NewsKind::PositionChanged {
+                user_id: Some(2),
+                changed_by: Some(1),
+                position: Some("Lieutenant"),
+            }
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        let text = "The faction upgraded its armory";
+        assert_eq!(NewsKind::parse(text), NewsKind::Unknown(text));
+    }
+}
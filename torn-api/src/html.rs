@@ -0,0 +1,310 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LinkTarget<'a> {
+    User(i32),
+    Faction(i32),
+    Company(i32),
+    Trade(i64),
+    Other(&'a str),
+}
+
+impl<'a> LinkTarget<'a> {
+    fn from_href(href: &'a str) -> Self {
+        let id = |name| query_param(href, name).and_then(|v| v.parse().ok());
+
+        if href.contains("profiles.php") {
+            id("XID").map(Self::User)
+        } else if href.contains("factions.php") {
+            id("ID").map(Self::Faction)
+        } else if href.contains("joblist.php") || href.contains("companies.php") {
+            id("ID").map(Self::Company)
+        } else if href.contains("trade.php") {
+            query_param(href, "ID")
+                .and_then(|v| v.parse().ok())
+                .map(Self::Trade)
+        } else {
+            None
+        }
+        .unwrap_or(Self::Other(href))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Segment<'a> {
+    Text(&'a str),
+    Link(LinkTarget<'a>),
+}
+
+/// The text and links of one of the HTML snippets that the API uses for events and news.
+#[derive(Debug)]
+pub(crate) struct Markup<'a> {
+    pub segments: Vec<Segment<'a>>,
+    /// Lowercase text with every link replaced by `{}`, used to match phrases without having to
+    /// worry about names that happen to contain them.
+    pub skeleton: String,
+}
+
+fn query_param<'a>(href: &'a str, name: &str) -> Option<&'a str> {
+    let mut offset = 0;
+    while let Some(pos) = href[offset..].find(name) {
+        let start = offset + pos;
+        let value_start = start + name.len() + 1;
+        offset = start + name.len();
+
+        let preceded_by_separator = href[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| matches!(c, '?' | '&' | ';' | '#' | '/'));
+
+        if preceded_by_separator && href[offset..].starts_with('=') {
+            let value = &href[value_start..];
+            let end = value
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(value.len());
+            return Some(&value[..end]);
+        }
+    }
+    None
+}
+
+fn href(tag: &str) -> &str {
+    let Some(pos) = tag.find("href") else {
+        return "";
+    };
+    let value = tag[pos + 4..].trim_start();
+    let Some(value) = value.strip_prefix('=') else {
+        return "";
+    };
+    let value = value.trim_start();
+
+    match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let value = &value[1..];
+            &value[..value.find(quote).unwrap_or(value.len())]
+        }
+        _ => &value[..value.find(char::is_whitespace).unwrap_or(value.len())],
+    }
+}
+
+pub(crate) fn parse(input: &str) -> Markup<'_> {
+    let mut segments = Vec::new();
+    let mut rest = input;
+
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            segments.push(Segment::Text(&rest[..start]));
+        }
+
+        let Some(tag_len) = rest[start..].find('>') else {
+            segments.push(Segment::Text(&rest[start..]));
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + tag_len].trim();
+        rest = &rest[start + tag_len + 1..];
+
+        let is_anchor = tag.get(..2).map_or(tag.eq_ignore_ascii_case("a"), |t| {
+            t.eq_ignore_ascii_case("a ")
+        });
+
+        if is_anchor {
+            segments.push(Segment::Link(LinkTarget::from_href(href(tag))));
+            rest = rest.find("</a>").map_or("", |end| &rest[end + 4..]);
+        }
+    }
+
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+
+    let mut skeleton = String::with_capacity(input.len());
+    for segment in &segments {
+        match segment {
+            Segment::Text(text) => skeleton.push_str(&text.to_lowercase()),
+            Segment::Link(_) => skeleton.push_str("{}"),
+        }
+    }
+
+    Markup { segments, skeleton }
+}
+
+fn parse_number(s: &str) -> Option<(i64, &str)> {
+    let end = s
+        .find(|c: char| !c.is_ascii_digit() && c != ',')
+        .unwrap_or(s.len());
+    let digits: String = s[..end].chars().filter(char::is_ascii_digit).collect();
+    digits.parse().ok().map(|n| (n, &s[end..]))
+}
+
+// some of the helpers are only used by the user event parser
+#[cfg_attr(not(feature = "user"), allow(dead_code))]
+impl<'a> Markup<'a> {
+    pub fn contains(&self, phrase: &str) -> bool {
+        self.skeleton.contains(phrase)
+    }
+
+    pub fn contains_any(&self, phrases: &[&str]) -> bool {
+        phrases.iter().any(|p| self.skeleton.contains(p))
+    }
+
+    pub fn links(&self) -> impl Iterator<Item = LinkTarget<'a>> + '_ {
+        self.segments.iter().filter_map(|s| match s {
+            Segment::Link(link) => Some(*link),
+            Segment::Text(_) => None,
+        })
+    }
+
+    pub fn texts(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.segments.iter().filter_map(|s| match s {
+            Segment::Text(text) => Some(*text),
+            Segment::Link(_) => None,
+        })
+    }
+
+    pub fn users(&self) -> impl Iterator<Item = i32> + '_ {
+        self.links().filter_map(|l| match l {
+            LinkTarget::User(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    pub fn user(&self, n: usize) -> Option<i32> {
+        self.users().nth(n)
+    }
+
+    /// The first user that is linked after `phrase`.
+    pub fn user_after(&self, phrase: &str) -> Option<i32> {
+        let pos = self.skeleton.find(phrase)?;
+        let skip = self.skeleton[..pos].matches("{}").count();
+        self.links().skip(skip).find_map(|l| match l {
+            LinkTarget::User(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    /// The first user that is linked before `phrase`.
+    pub fn user_before(&self, phrase: &str) -> Option<i32> {
+        let pos = self.skeleton.find(phrase)?;
+        let take = self.skeleton[..pos].matches("{}").count();
+        self.links().take(take).find_map(|l| match l {
+            LinkTarget::User(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    pub fn faction(&self) -> Option<i32> {
+        self.links().find_map(|l| match l {
+            LinkTarget::Faction(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    pub fn company(&self) -> Option<i32> {
+        self.links().find_map(|l| match l {
+            LinkTarget::Company(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    pub fn trade(&self) -> Option<i64> {
+        self.links().find_map(|l| match l {
+            LinkTarget::Trade(id) => Some(id),
+            _ => None,
+        })
+    }
+
+    /// The first dollar amount in the text, e.g. `$1,000,000`.
+    pub fn money(&self) -> Option<i64> {
+        self.texts().find_map(|text| {
+            text.match_indices('$')
+                .find_map(|(pos, _)| parse_number(&text[pos + 1..]).map(|(n, _)| n))
+        })
+    }
+
+    /// The first amount of points in the text, e.g. `1,000 points`.
+    pub fn points(&self) -> Option<i64> {
+        self.texts().find_map(|text| {
+            text.char_indices()
+                .filter(|(pos, c)| {
+                    c.is_ascii_digit()
+                        && text[..*pos]
+                            .chars()
+                            .next_back()
+                            .map_or(true, |p| !p.is_alphanumeric() && p != '$' && p != ',')
+                })
+                .find_map(|(pos, _)| {
+                    let (points, rest) = parse_number(&text[pos..])?;
+                    rest.starts_with(" point").then_some(points)
+                })
+        })
+    }
+
+    /// The first quantity and item name in the text, e.g. `10x Xanax` or `2 x Blood Bag : O+`.
+    pub fn items(&self) -> Option<(i32, &'a str)> {
+        const TERMINATORS: &[&str] = &[
+            " from ", " to ", " for ", " on ", " in ", " into ", " and ", " with ", ". ", ",",
+        ];
+
+        self.texts().find_map(|text| {
+            text.char_indices()
+                .filter(|(pos, c)| {
+                    c.is_ascii_digit()
+                        && text[..*pos]
+                            .chars()
+                            .next_back()
+                            .map_or(true, |p| !p.is_alphanumeric() && p != '$' && p != ',')
+                })
+                .find_map(|(pos, _)| {
+                    let (quantity, rest) = parse_number(&text[pos..])?;
+                    let rest = rest
+                        .strip_prefix("x ")
+                        .or_else(|| rest.strip_prefix(" x "))?;
+                    let end = TERMINATORS
+                        .iter()
+                        .filter_map(|t| rest.find(t))
+                        .min()
+                        .unwrap_or(rest.len());
+                    let item = rest[..end].trim().trim_end_matches('.');
+                    (!item.is_empty()).then_some((quantity.try_into().ok()?, item))
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links() {
+        let markup = parse(
+            "<a href = \"http://www.torn.com/profiles.php?XID=2111649\">Pyrit</a> of <a href = \
+             http://www.torn.com/factions.php?step=profile&ID=7049>Fact</a> [<a href = \
+             'http://www.torn.com/trade.php#step=view&ID=123'>view</a>]",
+        );
+
+        let targets: Vec<_> = markup.links().collect();
+        assert_eq!(
+            targets,
+            [
+                LinkTarget::User(2111649),
+                LinkTarget::Faction(7049),
+                LinkTarget::Trade(123)
+            ]
+        );
+        assert_eq!(markup.skeleton, "{} of {} [{}]");
+    }
+
+    #[test]
+    fn amounts() {
+        let markup = parse("You were sent $1,000,000 and 12x Xanax from someone.");
+
+        assert_eq!(markup.money(), Some(1_000_000));
+        assert_eq!(markup.items(), Some((12, "Xanax")));
+        assert_eq!(markup.points(), None);
+
+        let markup = parse("Someone deposited 1,500 points");
+
+        assert_eq!(markup.points(), Some(1_500));
+        assert_eq!(markup.money(), None);
+    }
+}
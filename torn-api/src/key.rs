@@ -1,14 +1,23 @@
 use std::collections::HashSet;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use torn_api_macros::ApiCategory;
 
+use crate::de_util::timestamp;
+
 #[derive(Debug, Clone, Copy, ApiCategory)]
 #[api(category = "key")]
 #[non_exhaustive]
 pub enum Selection {
     #[api(type = "Info", flatten)]
     Info,
+
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+
+    #[api(type = "Vec<KeySelection>", field = "selections")]
+    Lookup,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -35,6 +44,10 @@ pub enum AccessType {
 #[non_exhaustive]
 pub enum KeySelection {
     Info,
+    Timestamp,
+    Lookup,
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -253,4 +266,18 @@ mod tests {
 
         response.info().unwrap();
     }
+
+    #[async_test]
+    async fn lookup() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .key(|b| b.selections([Selection::Lookup, Selection::Timestamp]))
+            .await
+            .unwrap();
+
+        assert!(response.lookup().unwrap().contains(&KeySelection::Info));
+        response.timestamp().unwrap();
+    }
 }
@@ -13,6 +13,12 @@ pub mod faction;
 #[cfg(feature = "market")]
 pub mod market;
 
+#[cfg(feature = "company")]
+pub mod company;
+
+#[cfg(feature = "property")]
+pub mod property;
+
 #[cfg(feature = "torn")]
 pub mod torn;
 
@@ -30,6 +36,9 @@ pub mod common;
 
 mod de_util;
 
+#[cfg(any(feature = "user", feature = "faction"))]
+mod html;
+
 use std::fmt::Write;
 
 use chrono::{DateTime, Utc};
@@ -290,6 +299,32 @@ where
         self
     }
 
+    #[must_use]
+    pub fn log<L>(mut self, log_types: L) -> Self
+    where
+        L: IntoIterator<Item = i32>,
+    {
+        let log_types: Vec<_> = log_types.into_iter().map(|t| t.to_string()).collect();
+        self.request.add_query_item("log", log_types.join(","));
+        self
+    }
+
+    #[must_use]
+    pub fn cat(mut self, category: i32) -> Self {
+        self.request.add_query_item("cat", category);
+        self
+    }
+
+    /// The stat to list contributions for, required by the faction `contributors` selection.
+    #[must_use]
+    pub fn stat<S>(mut self, stat: S) -> Self
+    where
+        S: ToString,
+    {
+        self.request.add_query_item("stat", stat);
+        self
+    }
+
     #[must_use]
     pub fn comment(mut self, comment: String) -> Self {
         self.request.comment = Some(comment);
@@ -428,6 +463,20 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn url_builder_log() {
+        let url = ApiRequestBuilder::<user::Selection>::default()
+            .log([4800, 4810])
+            .cat(17)
+            .request
+            .url("", None);
+
+        assert_eq!(
+            "https://api.torn.com/user/?selections=&key=&log=4800,4810&cat=17",
+            url
+        );
+    }
+
     #[test]
     fn url_builder_duplicate() {
         let url = ApiRequestBuilder::<user::Selection>::default()
@@ -168,6 +168,78 @@ where
             .await
     }
 
+    #[cfg(feature = "company")]
+    pub async fn company<F>(&self, build: F) -> Result<crate::company::Response, E::Error>
+    where
+        F: FnOnce(
+            crate::ApiRequestBuilder<crate::company::Selection>,
+        ) -> crate::ApiRequestBuilder<crate::company::Selection>,
+    {
+        let mut builder = crate::ApiRequestBuilder::default();
+        builder = build(builder);
+
+        self.executor
+            .execute(self.client, builder.request, builder.id)
+            .await
+    }
+
+    #[cfg(feature = "company")]
+    pub async fn companies<F, L, I>(
+        &self,
+        ids: L,
+        build: F,
+    ) -> HashMap<I, Result<crate::company::Response, E::Error>>
+    where
+        F: FnOnce(
+            crate::ApiRequestBuilder<crate::company::Selection>,
+        ) -> crate::ApiRequestBuilder<crate::company::Selection>,
+        I: ToString + std::hash::Hash + std::cmp::Eq,
+        L: IntoIterator<Item = I>,
+    {
+        let mut builder = crate::ApiRequestBuilder::default();
+        builder = build(builder);
+
+        self.executor
+            .execute_many(self.client, builder.request, Vec::from_iter(ids))
+            .await
+    }
+
+    #[cfg(feature = "property")]
+    pub async fn property<F>(&self, build: F) -> Result<crate::property::Response, E::Error>
+    where
+        F: FnOnce(
+            crate::ApiRequestBuilder<crate::property::Selection>,
+        ) -> crate::ApiRequestBuilder<crate::property::Selection>,
+    {
+        let mut builder = crate::ApiRequestBuilder::default();
+        builder = build(builder);
+
+        self.executor
+            .execute(self.client, builder.request, builder.id)
+            .await
+    }
+
+    #[cfg(feature = "property")]
+    pub async fn properties<F, L, I>(
+        &self,
+        ids: L,
+        build: F,
+    ) -> HashMap<I, Result<crate::property::Response, E::Error>>
+    where
+        F: FnOnce(
+            crate::ApiRequestBuilder<crate::property::Selection>,
+        ) -> crate::ApiRequestBuilder<crate::property::Selection>,
+        I: ToString + std::hash::Hash + std::cmp::Eq,
+        L: IntoIterator<Item = I>,
+    {
+        let mut builder = crate::ApiRequestBuilder::default();
+        builder = build(builder);
+
+        self.executor
+            .execute_many(self.client, builder.request, Vec::from_iter(ids))
+            .await
+    }
+
     #[cfg(feature = "key")]
     pub async fn key<F>(&self, build: F) -> Result<crate::key::Response, E::Error>
     where
@@ -272,7 +344,7 @@ pub trait ApiClient {
 
     async fn request(&self, url: String) -> Result<serde_json::Value, Self::Error>;
 
-    fn torn_api<S>(&self, key: S) -> ApiProvider<Self, DirectExecutor<Self>>
+    fn torn_api<S>(&self, key: S) -> ApiProvider<'_, Self, DirectExecutor<Self>>
     where
         Self: Sized,
         S: ToString,
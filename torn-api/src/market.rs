@@ -1,11 +1,33 @@
-use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
 use torn_api_macros::ApiCategory;
 
+use crate::de_util::timestamp;
+
 #[derive(Debug, Clone, Copy, ApiCategory)]
 #[api(category = "market")]
 pub enum MarketSelection {
     #[api(type = "Vec<BazaarItem>", field = "bazaar")]
     Bazaar,
+
+    #[api(
+        type = "Vec<ItemMarketListing>",
+        field = "itemmarket",
+        with = "decode_item_market"
+    )]
+    ItemMarket,
+
+    #[api(type = "BTreeMap<i64, PointsMarketListing>", field = "pointsmarket")]
+    PointsMarket,
+
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+
+    #[cfg(feature = "key")]
+    #[api(type = "Vec<crate::key::MarketSelection>", field = "selections")]
+    Lookup,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -14,6 +36,28 @@ pub struct BazaarItem {
     pub quantity: u32,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemMarketListing {
+    #[serde(rename = "ID")]
+    pub id: i64,
+    pub cost: u64,
+    pub quantity: u32,
+}
+
+fn decode_item_market<'de, D>(deserializer: D) -> Result<Vec<ItemMarketListing>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PointsMarketListing {
+    pub cost: u64,
+    pub quantity: u32,
+    pub total_cost: u64,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -31,4 +75,30 @@ mod test {
 
         _ = response.bazaar().unwrap();
     }
+
+    #[async_test]
+    async fn market_item_market() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .market(|b| b.id(206).selections([MarketSelection::ItemMarket]))
+            .await
+            .unwrap();
+
+        _ = response.item_market().unwrap();
+    }
+
+    #[async_test]
+    async fn market_points_market() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .market(|b| b.selections([MarketSelection::PointsMarket]))
+            .await
+            .unwrap();
+
+        _ = response.points_market().unwrap();
+    }
 }
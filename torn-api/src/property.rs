@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+use torn_api_macros::{ApiCategory, IntoOwned};
+
+use crate::de_util::{self, timestamp};
+
+#[derive(Debug, Clone, Copy, ApiCategory)]
+#[api(category = "property")]
+#[non_exhaustive]
+pub enum PropertySelection {
+    #[api(type = "Property", field = "property")]
+    Property,
+
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+
+    #[cfg(feature = "key")]
+    #[api(type = "Vec<crate::key::PropertySelection>", field = "selections")]
+    Lookup,
+}
+
+pub type Selection = PropertySelection;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rental {
+    pub user_id: i32,
+    pub days_left: i16,
+    pub total_cost: i64,
+    pub cost_per_day: i64,
+}
+
+fn deserialize_rental<'de, D>(deserializer: D) -> Result<Option<Rental>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct RentalVisitor;
+
+    impl<'de> Visitor<'de> for RentalVisitor {
+        type Value = Option<Rental>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("struct Rental or empty array")
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            Rental::deserialize(serde::de::value::MapAccessDeserializer::new(map)).map(Some)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            match seq.size_hint() {
+                Some(0) | None => Ok(None),
+                Some(len) => Err(A::Error::invalid_length(len, &"empty array")),
+            }
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(None)
+        }
+    }
+
+    deserializer.deserialize_any(RentalVisitor)
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Property<'a> {
+    pub owner_id: i32,
+    pub property_type: i16,
+    pub happy: i32,
+    pub upkeep: i64,
+
+    #[serde(borrow, rename = "upgrades")]
+    pub modifications: Vec<&'a str>,
+    #[serde(borrow)]
+    pub staff: Vec<&'a str>,
+
+    #[serde(default, deserialize_with = "deserialize_rental")]
+    pub rented: Option<Rental>,
+
+    #[serde(default, deserialize_with = "de_util::comma_separated_list")]
+    pub users_living: Vec<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{async_test, setup, Client, ClientTrait};
+
+    #[async_test]
+    async fn property() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .property(|b| b.id(1).selections([Selection::Property]))
+            .await
+            .unwrap();
+
+        response.property().unwrap();
+    }
+
+    #[test]
+    fn rental() {
+        let decode = |value| deserialize_rental(&value);
+
+        assert!(decode(serde_json::json!([])).unwrap().is_none());
+        assert!(decode(serde_json::Value::Null).unwrap().is_none());
+
+        let rental = decode(serde_json::json!({
+            "user_id": 1,
+            "days_left": 10,
+            "total_cost": 1_000_000,
+            "cost_per_day": 100_000,
+        }))
+        .unwrap()
+        .unwrap();
+        assert_eq!(rental.days_left, 10);
+
+        assert!(decode(serde_json::json!({
+            "user_id": 1,
+            "days_left": "10",
+            "total_cost": 1_000_000,
+            "cost_per_day": 100_000,
+        }))
+        .is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize, Deserializer,
@@ -8,7 +8,15 @@ use serde::{
 
 use torn_api_macros::ApiCategory;
 
-use crate::{de_util, user};
+use crate::{
+    de_util::{self, empty_array_is_empty_map, timestamp},
+    user,
+};
+
+pub use crate::common::{
+    ChainReport, ChainReportBonus, ChainReportMember, ItemType, RankedWar, RankedWarFaction,
+    RankedWarInfo,
+};
 
 #[derive(Debug, Clone, Copy, ApiCategory)]
 #[api(category = "torn")]
@@ -43,6 +51,100 @@ pub enum TornSelection {
 
     #[api(type = "BTreeMap<i32, Item>", field = "items")]
     Items,
+
+    #[api(type = "BTreeMap<i32, &str>", field = "logcategories")]
+    LogCategories,
+
+    #[api(type = "BTreeMap<i32, &str>", field = "logtypes")]
+    LogTypes,
+
+    #[api(type = "BTreeMap<i32, RankedWar>", field = "rankedwars")]
+    RankedWars,
+
+    #[api(type = "RankedWarReport", field = "rankedwarreport")]
+    RankedWarReport,
+
+    #[api(
+        type = "BTreeMap<i32, Raid>",
+        field = "raids",
+        with = "empty_array_is_empty_map"
+    )]
+    Raids,
+
+    #[api(type = "RaidReport", field = "raidreport")]
+    RaidReport,
+
+    #[api(type = "ChainReport", field = "chainreport")]
+    ChainReport,
+
+    #[api(type = "BTreeMap<i32, Course>", field = "education")]
+    Education,
+
+    #[api(type = "BTreeMap<i32, Gym>", field = "gyms")]
+    Gyms,
+
+    #[api(type = "BTreeMap<i32, Honor>", field = "honors")]
+    Honors,
+
+    #[api(type = "BTreeMap<i32, Medal>", field = "medals")]
+    Medals,
+
+    #[api(type = "BTreeMap<i32, Property>", field = "properties")]
+    Properties,
+
+    #[api(type = "BTreeMap<i32, CompanyType>", field = "companies")]
+    Companies,
+
+    /// The faction upgrades by branch id and level.
+    #[api(
+        type = "BTreeMap<i32, BTreeMap<i16, FactionUpgrade>>",
+        field = "factiontree"
+    )]
+    FactionTree,
+
+    #[api(type = "BTreeMap<i32, Card>", field = "cards")]
+    Cards,
+
+    #[api(type = "BTreeMap<i32, PokerTable>", field = "pokertables")]
+    PokerTables,
+
+    #[api(type = "BankRates", field = "bank")]
+    Bank,
+
+    #[api(type = "BTreeMap<i32, Stock>", field = "stocks")]
+    Stocks,
+
+    #[api(type = "PawnShop", field = "pawnshop")]
+    PawnShop,
+
+    #[api(type = "BTreeMap<i32, CityShop>", field = "cityshops")]
+    CityShops,
+
+    /// Requires the item UID to be set with [crate::ApiRequestBuilder::id].
+    #[api(type = "ItemStats", field = "itemstats")]
+    ItemStats,
+
+    /// Requires the item UID to be set with [crate::ApiRequestBuilder::id].
+    #[api(type = "ItemDetails", field = "itemdetails")]
+    ItemDetails,
+
+    /// The historic value at a point in time can be requested with
+    /// [crate::ApiRequestBuilder::stats_timestamp].
+    #[api(type = "Stats", field = "stats")]
+    Stats,
+
+    #[api(type = "BTreeMap<i32, OrganisedCrime>", field = "organisedcrimes")]
+    OrganisedCrimes,
+
+    #[api(type = "Vec<&str>", field = "territorynames")]
+    TerritoryNames,
+
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+
+    #[cfg(feature = "key")]
+    #[api(type = "Vec<crate::key::TornSelection>", field = "selections")]
+    Lookup,
 }
 
 pub type Selection = TornSelection;
@@ -229,34 +331,125 @@ pub struct TerritoryWarReport {
     pub factions: HashMap<i32, TerritoryWarReportFaction>,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash)]
-#[non_exhaustive]
-pub enum ItemType {
-    Primary,
-    Secondary,
-    Melee,
-    Temporary,
-    Defensive,
-    Collectible,
-    Medical,
-    Drug,
-    Booster,
-    #[serde(rename = "Energy Drink")]
-    EnergyDrink,
-    Alcohol,
-    Book,
-    Candy,
-    Car,
-    Clothing,
-    Electronic,
-    Enhancer,
-    Flower,
-    Jewelry,
-    Other,
-    Special,
-    #[serde(rename = "Supply Pack")]
-    SupplyPack,
-    Virus,
+#[derive(Debug, Clone, Deserialize)]
+pub struct RankedWarReportMember {
+    pub name: String,
+    pub faction_id: i32,
+    pub level: i16,
+    pub attacks: i32,
+
+    #[cfg(feature = "decimal")]
+    pub score: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RankedWarReportItem {
+    pub name: String,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RankedWarReportRewards {
+    pub respect: i32,
+    pub points: i32,
+    #[serde(deserialize_with = "de_util::empty_array_is_empty_map")]
+    pub items: BTreeMap<i32, RankedWarReportItem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RankedWarReportFaction {
+    pub name: String,
+    pub score: i32,
+    pub attacks: i32,
+    pub rank_before: String,
+    pub rank_after: String,
+    pub rewards: RankedWarReportRewards,
+    pub members: BTreeMap<i32, RankedWarReportMember>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RankedWarReportWar {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+
+    pub winner: i32,
+    pub forfeit: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RankedWarReport {
+    pub factions: HashMap<i32, RankedWarReportFaction>,
+    pub war: RankedWarReportWar,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Raid {
+    pub assaulting_faction: i32,
+    pub defending_faction: i32,
+
+    #[cfg(feature = "decimal")]
+    pub assaulting_score: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub defending_score: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub assaulting_score: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub defending_score: f64,
+
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub started: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RaidReportRaid {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+
+    #[serde(default, deserialize_with = "de_util::zero_is_none")]
+    pub winner: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RaidReportMember {
+    pub name: String,
+    pub level: i16,
+    pub attacks: i32,
+
+    #[cfg(feature = "decimal")]
+    pub damage: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub damage: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RaidReportFaction {
+    pub name: String,
+    pub attacks: i32,
+    #[serde(rename = "type")]
+    pub role: TerritoryWarReportRole,
+    #[serde(default)]
+    pub members: BTreeMap<i32, RaidReportMember>,
+
+    #[cfg(feature = "decimal")]
+    pub score: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub score: f64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RaidReport {
+    pub raid: RaidReportRaid,
+    pub factions: HashMap<i32, RaidReportFaction>,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash)]
@@ -299,6 +492,325 @@ pub struct Item<'a> {
     pub image: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct Course<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    pub money_cost: i64,
+    pub tier: i16,
+    #[serde(deserialize_with = "de_util::duration_seconds")]
+    pub duration: Duration,
+    #[serde(borrow, default, deserialize_with = "empty_array_is_empty_map")]
+    pub results: BTreeMap<String, Vec<&'a str>>,
+    #[serde(default)]
+    pub prerequisites: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Gym<'a> {
+    pub name: &'a str,
+    pub stage: i16,
+    pub cost: i64,
+    pub energy: i16,
+    pub strength: i16,
+    pub speed: i16,
+    pub defense: i16,
+    pub dexterity: i16,
+    pub note: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[non_exhaustive]
+pub enum AwardRarity {
+    Common,
+    Limited,
+    Rare,
+    #[serde(rename = "Very Rare")]
+    VeryRare,
+    #[serde(rename = "Extremely Rare")]
+    ExtremelyRare,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Honor<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    #[serde(rename = "type")]
+    pub honor_type: i16,
+    pub circulation: i32,
+    pub rarity: AwardRarity,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Medal<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    #[serde(rename = "type")]
+    pub medal_type: &'a str,
+    pub circulation: i32,
+    pub rarity: AwardRarity,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Property<'a> {
+    pub name: &'a str,
+    pub cost: i64,
+    pub happy: i32,
+    pub upkeep: i64,
+    #[serde(borrow)]
+    pub upgrades_available: Vec<&'a str>,
+    #[serde(borrow)]
+    pub staff_available: Vec<&'a str>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompanyPosition<'a> {
+    pub man_required: i32,
+    pub int_required: i32,
+    pub end_required: i32,
+    pub man_gain: i16,
+    pub int_gain: i16,
+    pub end_gain: i16,
+    pub special_ability: &'a str,
+    pub description: &'a str,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompanyStock {
+    pub cost: i64,
+    pub rrp: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompanySpecial<'a> {
+    pub effect: &'a str,
+    pub cost: i32,
+    pub rating_required: i16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompanyType<'a> {
+    pub name: &'a str,
+    pub cost: i64,
+    pub default_employees: i16,
+    #[serde(borrow)]
+    pub positions: BTreeMap<String, CompanyPosition<'a>>,
+    #[serde(default, deserialize_with = "empty_array_is_empty_map")]
+    pub stock: BTreeMap<String, CompanyStock>,
+    #[serde(borrow)]
+    pub specials: BTreeMap<String, CompanySpecial<'a>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FactionUpgrade<'a> {
+    pub branch: &'a str,
+    pub name: &'a str,
+    pub ability: &'a str,
+    pub challenge: &'a str,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Card<'a> {
+    pub name: &'a str,
+    pub short: &'a str,
+    pub rank: &'a str,
+    pub class: &'a str,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PokerTable<'a> {
+    pub name: &'a str,
+    pub big_blind: i64,
+    pub small_blind: i64,
+    pub speed: i16,
+    pub rake_cap: i64,
+    pub rake_percentage: i16,
+    pub max_players: i16,
+}
+
+/// The interest rates of the city bank in percent, by investment term.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BankRates {
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "1w")]
+    pub one_week: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "2w")]
+    pub two_weeks: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "1m")]
+    pub one_month: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "2m")]
+    pub two_months: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "3m")]
+    pub three_months: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "1w", deserialize_with = "de_util::string_or_f64")]
+    pub one_week: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "2w", deserialize_with = "de_util::string_or_f64")]
+    pub two_weeks: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "1m", deserialize_with = "de_util::string_or_f64")]
+    pub one_month: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "2m", deserialize_with = "de_util::string_or_f64")]
+    pub two_months: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "3m", deserialize_with = "de_util::string_or_f64")]
+    pub three_months: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StockBenefitType {
+    Active,
+    Passive,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockBenefit<'a> {
+    #[serde(rename = "type")]
+    pub benefit_type: StockBenefitType,
+    pub frequency: i16,
+    pub requirement: i64,
+    pub description: &'a str,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Stock<'a> {
+    pub stock_id: i32,
+    pub name: &'a str,
+    pub acronym: &'a str,
+    pub market_cap: i64,
+    pub total_shares: i64,
+    pub investors: i32,
+    #[serde(borrow)]
+    pub benefit: StockBenefit<'a>,
+
+    #[cfg(feature = "decimal")]
+    pub current_price: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub current_price: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PawnShop {
+    pub points_value: i64,
+    pub donatorpack_value: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShopItem<'a> {
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: &'a str,
+    pub price: i64,
+    pub in_stock: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CityShop<'a> {
+    pub name: &'a str,
+    #[serde(borrow, default, deserialize_with = "empty_array_is_empty_map")]
+    pub inventory: BTreeMap<i32, ShopItem<'a>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemStatValues {
+    pub damage: Option<f32>,
+    pub accuracy: Option<f32>,
+    pub armor: Option<f32>,
+    pub quality: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemStats<'a> {
+    #[serde(rename = "ID")]
+    pub uid: i64,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: &'a str,
+    pub stats: ItemStatValues,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[non_exhaustive]
+pub enum ItemRarity {
+    #[serde(rename = "None")]
+    Plain,
+    Yellow,
+    Orange,
+    Red,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemBonus<'a> {
+    pub bonus: &'a str,
+    pub description: &'a str,
+    pub value: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemDetails<'a> {
+    #[serde(rename = "ID")]
+    pub uid: i64,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: &'a str,
+    pub rarity: ItemRarity,
+    pub damage: Option<f32>,
+    pub accuracy: Option<f32>,
+    pub armor: Option<f32>,
+    pub quality: f32,
+    #[serde(borrow, default, deserialize_with = "empty_array_is_empty_map")]
+    pub bonuses: BTreeMap<String, ItemBonus<'a>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Stats {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+
+    pub users_total: i64,
+    pub users_male: i64,
+    pub users_female: i64,
+    #[serde(rename = "users_marriedcouples")]
+    pub users_married_couples: i64,
+    pub users_daily: i64,
+
+    pub money_onhand: i64,
+    pub money_average: i64,
+    pub money_citybank: i64,
+    pub items: i64,
+    pub points_total: i64,
+    pub points_market: i64,
+
+    /// All other counters, whose set changes whenever new features are added to the game.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrganisedCrime<'a> {
+    pub name: &'a str,
+    pub members: i16,
+    /// The planning time in hours.
+    pub time: i16,
+    pub min_cash: i64,
+    pub max_cash: i64,
+    pub min_respect: i32,
+    pub max_respect: i32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,6 +913,62 @@ mod tests {
         );
     }
 
+    #[async_test]
+    async fn ranked_wars() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([Selection::RankedWars]))
+            .await
+            .unwrap();
+
+        response.ranked_wars().unwrap();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([Selection::RankedWarReport]).id(8418))
+            .await
+            .unwrap();
+
+        let report = response.ranked_war_report().unwrap();
+        assert!(report.factions.contains_key(&report.war.winner));
+    }
+
+    #[async_test]
+    async fn raids() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([Selection::Raids]))
+            .await
+            .unwrap();
+
+        response.raids().unwrap();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([Selection::RaidReport]).id(1234))
+            .await
+            .unwrap();
+
+        assert_eq!(response.raid_report().unwrap().factions.len(), 2);
+    }
+
+    #[async_test]
+    async fn chain_report() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| b.selections([Selection::ChainReport]).id(20000000))
+            .await
+            .unwrap();
+
+        response.chain_report().unwrap();
+    }
+
     #[async_test]
     async fn item() {
         let key = setup();
@@ -414,4 +982,185 @@ mod tests {
         let item_list = response.items().unwrap();
         assert!(item_list.contains_key(&837));
     }
+
+    #[async_test]
+    async fn logs() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| b.selections([Selection::LogCategories, Selection::LogTypes]))
+            .await
+            .unwrap();
+
+        response.log_categories().unwrap();
+        assert!(response.log_types().unwrap().contains_key(&4800));
+    }
+
+    #[async_test]
+    async fn catalogue() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| {
+                b.selections([
+                    Selection::Education,
+                    Selection::Gyms,
+                    Selection::Properties,
+                    Selection::Companies,
+                    Selection::FactionTree,
+                    Selection::Cards,
+                    Selection::PokerTables,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.education().unwrap();
+        response.gyms().unwrap();
+        response.properties().unwrap();
+        response.companies().unwrap();
+        response.faction_tree().unwrap();
+        response.cards().unwrap();
+        response.poker_tables().unwrap();
+    }
+
+    #[async_test]
+    async fn awards() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([Selection::Medals, Selection::Honors]))
+            .await
+            .unwrap();
+
+        let medals = response.medals().unwrap();
+        let honors = response.honors().unwrap();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .user(|b| b.selections([user::Selection::Medals, user::Selection::Honors]))
+            .await
+            .unwrap();
+
+        for award in response.medals().unwrap().values() {
+            assert!(award.details(&medals).is_some());
+        }
+        for award in response.honors().unwrap().values() {
+            assert!(award.details(&honors).is_some());
+        }
+    }
+
+    #[async_test]
+    async fn economy() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| {
+                b.selections([
+                    Selection::Bank,
+                    Selection::Stocks,
+                    Selection::PawnShop,
+                    Selection::CityShops,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.bank().unwrap();
+        response.stocks().unwrap();
+        response.pawn_shop().unwrap();
+        response.city_shops().unwrap();
+    }
+
+    #[async_test]
+    async fn item_details() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| {
+                b.selections([Selection::ItemStats, Selection::ItemDetails])
+                    .id(8_823_012_437_i64)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.item_stats().unwrap().uid,
+            response.item_details().unwrap().uid
+        );
+    }
+
+    #[async_test]
+    async fn stats() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| {
+                b.selections([
+                    Selection::Stats,
+                    Selection::OrganisedCrimes,
+                    Selection::TerritoryNames,
+                ])
+                .stats_timestamp(1_700_000_000)
+            })
+            .await
+            .unwrap();
+
+        response.stats().unwrap();
+        response.organised_crimes().unwrap();
+        assert!(response.territory_names().unwrap().contains(&"NSC"));
+    }
+
+    #[cfg(feature = "key")]
+    #[async_test]
+    async fn lookup() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .torn(|b| b.selections([Selection::Lookup, Selection::Timestamp]))
+            .await
+            .unwrap();
+
+        assert!(response
+            .lookup()
+            .unwrap()
+            .contains(&crate::key::TornSelection::Timestamp));
+        response.timestamp().unwrap();
+    }
+
+    #[async_test]
+    async fn education_and_companies() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([Selection::Education, Selection::Companies]))
+            .await
+            .unwrap();
+
+        let courses = response.education().unwrap();
+        let companies = response.companies().unwrap();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .user(|b| b.selections([user::Selection::Education, user::Selection::JobPoints]))
+            .await
+            .unwrap();
+
+        let education = response.education().unwrap();
+        assert_eq!(
+            education.completed_course_details(&courses).count(),
+            education.completed_courses.len()
+        );
+        for (_, company) in response.job_points().unwrap().company_details(&companies) {
+            assert!(company.is_some());
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Duration, Utc};
 use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize, Deserializer,
@@ -9,9 +10,14 @@ use std::{
 
 use torn_api_macros::{ApiCategory, IntoOwned};
 
-use crate::de_util;
+use crate::de_util::{self, empty_array_is_empty_map, empty_dict_is_empty_array, timestamp};
 
-pub use crate::common::{Attack, AttackFull, LastAction, Status};
+pub use crate::common::{
+    Attack, AttackFull, ItemType, LastAction, Report, ReportType, Revive, ReviveFull, ReviveResult,
+    Status,
+};
+
+pub mod event;
 
 #[derive(Debug, Clone, Copy, ApiCategory)]
 #[api(category = "user")]
@@ -37,6 +43,135 @@ pub enum UserSelection {
     Medals,
     #[api(type = "Awards<Honors>", flatten)]
     Honors,
+    #[api(type = "Bars", flatten)]
+    Bars,
+    #[api(type = "Cooldowns", field = "cooldowns")]
+    Cooldowns,
+    #[api(type = "Travel", field = "travel")]
+    Travel,
+    #[api(type = "Refills", field = "refills")]
+    Refills,
+    #[api(type = "Notifications", field = "notifications")]
+    Notifications,
+    #[api(
+        type = "BTreeMap<String, Event>",
+        field = "events",
+        with = "empty_array_is_empty_map"
+    )]
+    Events,
+    #[api(
+        type = "BTreeMap<String, Event>",
+        field = "events",
+        with = "empty_array_is_empty_map"
+    )]
+    NewEvents,
+    #[api(
+        type = "BTreeMap<String, Event>",
+        field = "events",
+        with = "empty_array_is_empty_map"
+    )]
+    ReceivedEvents,
+    #[api(
+        type = "BTreeMap<String, Message>",
+        field = "messages",
+        with = "empty_array_is_empty_map"
+    )]
+    Messages,
+    #[api(
+        type = "BTreeMap<String, Message>",
+        field = "messages",
+        with = "empty_array_is_empty_map"
+    )]
+    NewMessages,
+    #[api(
+        type = "Vec<InventoryItem>",
+        field = "inventory",
+        with = "empty_dict_is_empty_array"
+    )]
+    Inventory,
+    #[api(type = "Vec<Ammo>", field = "ammo", with = "empty_dict_is_empty_array")]
+    Ammo,
+    #[api(
+        type = "Vec<DisplayItem>",
+        field = "display",
+        with = "empty_dict_is_empty_array"
+    )]
+    Display,
+    #[api(
+        type = "Vec<BazaarItem>",
+        field = "bazaar",
+        with = "empty_dict_is_empty_array"
+    )]
+    Bazaar,
+    #[api(
+        type = "BTreeMap<String, LogEntry>",
+        field = "log",
+        with = "empty_array_is_empty_map"
+    )]
+    Log,
+    #[api(type = "BattleStats", flatten)]
+    BattleStats,
+    #[api(type = "i16", field = "active_gym")]
+    Gym,
+    #[api(type = "WorkStats", flatten)]
+    WorkStats,
+    #[api(type = "Perks", flatten)]
+    Perks,
+    #[api(type = "BTreeMap<String, i16>", field = "merits")]
+    Merits,
+    #[api(type = "Money", flatten)]
+    Money,
+    #[api(type = "Networth", field = "networth")]
+    Networth,
+    #[api(
+        type = "BTreeMap<i32, Stock>",
+        field = "stocks",
+        with = "empty_array_is_empty_map"
+    )]
+    Stocks,
+    #[api(
+        type = "BTreeMap<i32, Property>",
+        field = "properties",
+        with = "empty_array_is_empty_map"
+    )]
+    Properties,
+    #[api(type = "Education", flatten)]
+    Education,
+    #[api(type = "BTreeMap<String, f32>", flatten, with = "decode_skills")]
+    Skills,
+    #[api(type = "JobPoints", field = "jobpoints")]
+    JobPoints,
+    #[api(
+        type = "Vec<WeaponExp>",
+        field = "weaponexp",
+        with = "empty_dict_is_empty_array"
+    )]
+    WeaponExp,
+    #[api(
+        type = "BTreeMap<i32, Revive>",
+        field = "revives",
+        with = "empty_array_is_empty_map"
+    )]
+    RevivesFull,
+    #[api(
+        type = "BTreeMap<i32, ReviveFull>",
+        field = "revives",
+        with = "empty_array_is_empty_map"
+    )]
+    Revives,
+    #[api(type = "BTreeMap<String, HallOfFame>", field = "halloffame")]
+    Hof,
+    #[api(
+        type = "Vec<Report>",
+        field = "reports",
+        with = "empty_dict_is_empty_array"
+    )]
+    Reports,
+    #[api(type = "DateTime<Utc>", field = "timestamp", with = "timestamp")]
+    Timestamp,
+    #[cfg(feature = "key")]
+    #[api(type = "Vec<crate::key::UserSelection>", field = "selections")]
+    Lookup,
 }
 
 pub type Selection = UserSelection;
@@ -602,9 +737,18 @@ pub struct EmploymentStatus {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Award {
+    pub id: i32,
     pub award_time: chrono::DateTime<chrono::Utc>,
 }
 
+impl Award {
+    /// Joins the award with its entry in the catalogue returned by the `medals` or `honors`
+    /// torn selection, e.g. to get its name and rarity.
+    pub fn details<'a, D>(&self, catalogue: &'a BTreeMap<i32, D>) -> Option<&'a D> {
+        catalogue.get(&self.id)
+    }
+}
+
 pub trait AwardMarker {
     fn award_key() -> &'static str;
     fn time_key() -> &'static str;
@@ -715,13 +859,13 @@ where
                 let times = times.ok_or_else(|| de::Error::missing_field(T::time_key()))?;
 
                 Ok(Awards {
-                    inner: zip(
-                        awards,
-                        times.into_iter().map(|t| Award {
-                            award_time: chrono::DateTime::from_timestamp(t, 0).unwrap_or_default(),
-                        }),
-                    )
-                    .collect(),
+                    inner: zip(awards, times)
+                        .map(|(id, t)| {
+                            let award_time =
+                                chrono::DateTime::from_timestamp(t, 0).unwrap_or_default();
+                            (id, Award { id, award_time })
+                        })
+                        .collect(),
                     marker: Default::default(),
                 })
             }
@@ -731,6 +875,547 @@ where
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bar {
+    pub current: i32,
+    pub maximum: i32,
+    pub increment: i32,
+    #[serde(deserialize_with = "de_util::duration_seconds")]
+    pub interval: Duration,
+    #[serde(deserialize_with = "de_util::duration_seconds")]
+    pub ticktime: Duration,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub fulltime: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainBar {
+    pub current: i32,
+    pub maximum: i32,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub timeout: Option<Duration>,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub cooldown: Option<Duration>,
+
+    #[cfg(feature = "decimal")]
+    pub modifier: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub modifier: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bars {
+    pub life: Bar,
+    pub energy: Bar,
+    pub nerve: Bar,
+    pub happy: Bar,
+    pub chain: ChainBar,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Cooldowns {
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub drug: Option<Duration>,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub medical: Option<Duration>,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub booster: Option<Duration>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Travel<'a> {
+    pub destination: &'a str,
+    #[serde(rename = "timestamp", deserialize_with = "de_util::zero_date_is_none")]
+    pub arrival: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "de_util::zero_date_is_none")]
+    pub departed: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub time_left: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Refills {
+    pub energy_refill_used: bool,
+    pub nerve_refill_used: bool,
+    pub token_refill_used: bool,
+    pub special_refills_available: i16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Notifications {
+    pub messages: i32,
+    pub events: i32,
+    pub awards: i32,
+    pub competition: i32,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Event<'a> {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    #[serde(rename = "event")]
+    pub text: &'a str,
+    #[serde(default, deserialize_with = "de_util::int_is_bool")]
+    pub seen: bool,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Message<'a> {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    #[serde(rename = "ID")]
+    pub sender_id: i32,
+    #[serde(rename = "name")]
+    pub sender_name: &'a str,
+    #[serde(rename = "type")]
+    pub message_type: &'a str,
+    pub title: &'a str,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub seen: bool,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub read: bool,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct InventoryItem<'a> {
+    #[serde(rename = "ID")]
+    pub item_id: i32,
+    #[serde(rename = "UID", default)]
+    pub uid: Option<i64>,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: ItemType,
+    pub quantity: i32,
+    #[serde(deserialize_with = "de_util::zero_is_none")]
+    pub equipped: Option<i16>,
+    pub market_price: i64,
+}
+
+impl<'a> InventoryItem<'a> {
+    /// Joins the item with its entry in the catalogue returned by the `items` torn selection.
+    pub fn details<'c, D>(&self, items: &'c BTreeMap<i32, D>) -> Option<&'c D> {
+        items.get(&self.item_id)
+    }
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Ammo<'a> {
+    #[serde(rename = "ammoID")]
+    pub ammo_id: i32,
+    #[serde(rename = "typeID")]
+    pub type_id: i32,
+    pub size: &'a str,
+    #[serde(rename = "type")]
+    pub ammo_type: &'a str,
+    pub quantity: i32,
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub equipped: bool,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct DisplayItem<'a> {
+    #[serde(rename = "ID")]
+    pub item_id: i32,
+    #[serde(rename = "UID", default)]
+    pub uid: Option<i64>,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: ItemType,
+    pub quantity: i32,
+    pub circulation: i64,
+    pub market_price: i64,
+}
+
+impl<'a> DisplayItem<'a> {
+    /// See [InventoryItem::details].
+    pub fn details<'c, D>(&self, items: &'c BTreeMap<i32, D>) -> Option<&'c D> {
+        items.get(&self.item_id)
+    }
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct BazaarItem<'a> {
+    #[serde(rename = "ID")]
+    pub item_id: i32,
+    #[serde(rename = "UID", default)]
+    pub uid: Option<i64>,
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub item_type: ItemType,
+    pub quantity: i32,
+    pub price: i64,
+    pub market_price: i64,
+}
+
+impl<'a> BazaarItem<'a> {
+    /// See [InventoryItem::details].
+    pub fn details<'c, D>(&self, items: &'c BTreeMap<i32, D>) -> Option<&'c D> {
+        items.get(&self.item_id)
+    }
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct LogEntry<'a> {
+    #[serde(rename = "log")]
+    pub log_type: i32,
+    pub title: &'a str,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    pub category: &'a str,
+    pub data: serde_json::Value,
+    pub params: serde_json::Value,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct BattleStats<'a> {
+    pub strength: i64,
+    pub defense: i64,
+    pub speed: i64,
+    pub dexterity: i64,
+    pub total: i64,
+
+    pub strength_modifier: i16,
+    pub defense_modifier: i16,
+    pub speed_modifier: i16,
+    pub dexterity_modifier: i16,
+
+    #[serde(borrow)]
+    pub strength_info: Vec<&'a str>,
+    #[serde(borrow)]
+    pub defense_info: Vec<&'a str>,
+    #[serde(borrow)]
+    pub speed_info: Vec<&'a str>,
+    #[serde(borrow)]
+    pub dexterity_info: Vec<&'a str>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkStats {
+    pub manual_labor: i32,
+    pub intelligence: i32,
+    pub endurance: i32,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Perks<'a> {
+    #[serde(borrow, default)]
+    pub faction_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub job_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub property_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub education_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub book_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub merit_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub stock_perks: Vec<&'a str>,
+    #[serde(borrow, default)]
+    pub enhancer_perks: Vec<&'a str>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CityBank {
+    pub amount: i64,
+    #[serde(deserialize_with = "de_util::zero_duration_is_none")]
+    pub time_left: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Money {
+    pub points: i32,
+    #[serde(rename = "money_onhand")]
+    pub cash: i64,
+    #[serde(rename = "vault_amount")]
+    pub vault: i64,
+    pub cayman_bank: i64,
+    pub city_bank: CityBank,
+    pub company_funds: i64,
+
+    #[cfg(feature = "decimal")]
+    pub daily_networth: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub daily_networth: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Networth {
+    #[cfg(feature = "decimal")]
+    pub pending: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub wallet: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub bank: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub points: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub cayman: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub vault: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub piggybank: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub items: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "displaycase")]
+    pub display_case: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub bazaar: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "itemmarket", default)]
+    pub item_market: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub properties: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "stockmarket")]
+    pub stock_market: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "auctionhouse")]
+    pub auction_house: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub company: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub bookie: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "enlistedcars")]
+    pub enlisted_cars: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub loan: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    #[serde(rename = "unpaidfees")]
+    pub unpaid_fees: rust_decimal::Decimal,
+    #[cfg(feature = "decimal")]
+    pub total: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub pending: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub wallet: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub bank: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub points: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub cayman: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub vault: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub piggybank: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub items: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "displaycase")]
+    pub display_case: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub bazaar: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "itemmarket", default)]
+    pub item_market: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub properties: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "stockmarket")]
+    pub stock_market: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "auctionhouse")]
+    pub auction_house: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub company: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub bookie: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "enlistedcars")]
+    pub enlisted_cars: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub loan: f64,
+    #[cfg(not(feature = "decimal"))]
+    #[serde(rename = "unpaidfees")]
+    pub unpaid_fees: f64,
+    #[cfg(not(feature = "decimal"))]
+    pub total: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockIncrement {
+    #[serde(deserialize_with = "de_util::int_is_bool")]
+    pub ready: bool,
+    pub increment: i32,
+    pub progress: i16,
+    pub frequency: i16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockTransaction {
+    pub shares: i64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub time_bought: DateTime<Utc>,
+
+    #[cfg(feature = "decimal")]
+    pub bought_price: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    pub bought_price: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Stock {
+    pub stock_id: i32,
+    pub total_shares: i64,
+    pub dividend: Option<StockIncrement>,
+    pub benefit: Option<StockIncrement>,
+    #[serde(deserialize_with = "empty_array_is_empty_map")]
+    pub transactions: BTreeMap<i64, StockTransaction>,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct Property<'a> {
+    pub owner_id: i32,
+    pub property_type: i16,
+    pub property: &'a str,
+    pub status: &'a str,
+    pub happy: i32,
+    pub upkeep: i64,
+    pub staff_cost: i64,
+    pub cost: i64,
+    #[serde(rename = "marketprice")]
+    pub market_price: i64,
+    pub modifications: BTreeMap<String, i16>,
+    pub staff: BTreeMap<String, i16>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Education {
+    #[serde(
+        rename = "education_current",
+        deserialize_with = "de_util::zero_is_none"
+    )]
+    pub current_course: Option<i32>,
+    #[serde(
+        rename = "education_timeleft",
+        deserialize_with = "de_util::zero_duration_is_none"
+    )]
+    pub time_left: Option<Duration>,
+    #[serde(rename = "education_completed")]
+    pub completed_courses: Vec<i32>,
+}
+
+impl Education {
+    /// Joins the current course with its entry in the catalogue returned by the `education`
+    /// torn selection.
+    pub fn current_course_details<'a, D>(&self, courses: &'a BTreeMap<i32, D>) -> Option<&'a D> {
+        self.current_course.and_then(|id| courses.get(&id))
+    }
+
+    /// Joins the completed courses with their entries in the catalogue returned by the
+    /// `education` torn selection.
+    pub fn completed_course_details<'a, D>(
+        &'a self,
+        courses: &'a BTreeMap<i32, D>,
+    ) -> impl Iterator<Item = &'a D> + 'a {
+        self.completed_courses
+            .iter()
+            .filter_map(|id| courses.get(id))
+    }
+}
+
+/// String fields of the other flattened selections, which could otherwise be mistaken for skills.
+const NON_SKILL_KEYS: &[&str] = &["name", "gender", "rank", "role", "signup", "property"];
+
+fn decode_skills<'de, D>(deserializer: D) -> Result<BTreeMap<String, f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct SkillsVisitor;
+
+    impl<'de> Visitor<'de> for SkillsVisitor {
+        type Value = BTreeMap<String, f32>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("map of skills")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut skills = BTreeMap::new();
+
+            while let Some(key) = map.next_key::<String>()? {
+                // skill levels are encoded as numeric strings. Since the selection is flattened
+                // the map also contains the fields of any other selections in the request, so
+                // the string fields of those and anything that isn't a finite number are skipped
+                let value: serde_json::Value = map.next_value()?;
+                if NON_SKILL_KEYS.contains(&key.as_str()) {
+                    continue;
+                }
+                if let Some(level) = value
+                    .as_str()
+                    .and_then(|l| l.parse::<f32>().ok())
+                    .filter(|l| l.is_finite())
+                {
+                    skills.insert(key, level);
+                }
+            }
+
+            Ok(skills)
+        }
+    }
+
+    deserializer.deserialize_map(SkillsVisitor)
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct CompanyJobPoints<'a> {
+    pub name: &'a str,
+    pub jobpoints: i32,
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct JobPoints<'a> {
+    #[serde(deserialize_with = "empty_array_is_empty_map")]
+    pub jobs: BTreeMap<String, i32>,
+    #[serde(borrow, deserialize_with = "empty_array_is_empty_map")]
+    pub companies: BTreeMap<i16, CompanyJobPoints<'a>>,
+}
+
+impl<'a> JobPoints<'a> {
+    /// Joins the job points per company type with the catalogue returned by the `companies`
+    /// torn selection.
+    pub fn company_details<'s, D>(
+        &'s self,
+        companies: &'s BTreeMap<i32, D>,
+    ) -> impl Iterator<Item = (&'s CompanyJobPoints<'a>, Option<&'s D>)> + 's {
+        self.companies
+            .iter()
+            .map(|(id, points)| (points, companies.get(&(*id).into())))
+    }
+}
+
+#[derive(Debug, IntoOwned, Deserialize)]
+pub struct WeaponExp<'a> {
+    #[serde(rename = "itemID")]
+    pub item_id: i32,
+    pub name: &'a str,
+    pub exp: i16,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HallOfFame {
+    pub rank: i32,
+
+    #[cfg(feature = "decimal")]
+    #[serde(deserialize_with = "de_util::string_or_decimal")]
+    pub value: rust_decimal::Decimal,
+
+    #[cfg(not(feature = "decimal"))]
+    #[serde(deserialize_with = "de_util::string_or_f64")]
+    pub value: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -768,6 +1453,212 @@ mod tests {
         response.honors().unwrap();
     }
 
+    #[async_test]
+    async fn user_private() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([
+                    Selection::Bars,
+                    Selection::Cooldowns,
+                    Selection::Travel,
+                    Selection::Refills,
+                    Selection::Notifications,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.bars().unwrap();
+        response.cooldowns().unwrap();
+        response.travel().unwrap();
+        response.refills().unwrap();
+        response.notifications().unwrap();
+    }
+
+    #[async_test]
+    async fn events() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([Selection::Events, Selection::Messages])
+                    .from(DateTime::from_timestamp(1_700_000_000, 0).unwrap())
+            })
+            .await
+            .unwrap();
+
+        response.events().unwrap();
+        response.messages().unwrap();
+    }
+
+    #[async_test]
+    async fn items() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([
+                    Selection::Inventory,
+                    Selection::Ammo,
+                    Selection::Display,
+                    Selection::Bazaar,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.inventory().unwrap();
+        response.ammo().unwrap();
+        response.display().unwrap();
+        response.bazaar().unwrap();
+    }
+
+    #[async_test]
+    async fn item_details() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .torn(|b| b.selections([crate::torn::Selection::Items]))
+            .await
+            .unwrap();
+        let items = response.items().unwrap();
+
+        let response = Client::default()
+            .torn_api(&key)
+            .user(|b| b.selections([Selection::Inventory]))
+            .await
+            .unwrap();
+
+        for item in response.inventory().unwrap() {
+            assert_eq!(
+                item.details(&items).map(|i| i.name.as_str()),
+                Some(item.name)
+            );
+        }
+    }
+
+    #[async_test]
+    async fn log() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([Selection::Log])
+                    .log([4800, 4810])
+                    .to(DateTime::from_timestamp(1_700_000_000, 0).unwrap())
+            })
+            .await
+            .unwrap();
+
+        response.log().unwrap();
+    }
+
+    #[async_test]
+    async fn stats() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([
+                    Selection::BattleStats,
+                    Selection::Gym,
+                    Selection::WorkStats,
+                    Selection::Perks,
+                    Selection::Merits,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.battle_stats().unwrap();
+        response.gym().unwrap();
+        response.work_stats().unwrap();
+        response.perks().unwrap();
+        response.merits().unwrap();
+    }
+
+    #[async_test]
+    async fn money() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([
+                    Selection::Money,
+                    Selection::Networth,
+                    Selection::Stocks,
+                    Selection::Properties,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.money().unwrap();
+        response.networth().unwrap();
+        response.stocks().unwrap();
+        response.properties().unwrap();
+    }
+
+    #[async_test]
+    async fn education() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| {
+                b.selections([
+                    Selection::Education,
+                    Selection::Skills,
+                    Selection::JobPoints,
+                    Selection::WeaponExp,
+                ])
+            })
+            .await
+            .unwrap();
+
+        response.education().unwrap();
+        response.skills().unwrap();
+        response.job_points().unwrap();
+        response.weapon_exp().unwrap();
+    }
+
+    #[async_test]
+    async fn revives() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| b.selections([Selection::Revives, Selection::Hof]))
+            .await
+            .unwrap();
+
+        response.revives().unwrap();
+        response.revives_full().unwrap();
+        response.hof().unwrap();
+    }
+
+    #[async_test]
+    async fn reports() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| b.selections([Selection::Reports]))
+            .await
+            .unwrap();
+
+        response.reports().unwrap();
+    }
+
     #[async_test]
     async fn not_in_faction() {
         let key = setup();
@@ -825,4 +1716,44 @@ mod tests {
 
         assert!(icons.contains_key(&Icon::FEDDED))
     }
+
+    #[cfg(feature = "key")]
+    #[async_test]
+    async fn lookup() {
+        let key = setup();
+
+        let response = Client::default()
+            .torn_api(key)
+            .user(|b| b.selections([Selection::Lookup, Selection::Timestamp]))
+            .await
+            .unwrap();
+
+        assert!(response
+            .lookup()
+            .unwrap()
+            .contains(&crate::key::UserSelection::Timestamp));
+        response.timestamp().unwrap();
+    }
+
+    #[test]
+    fn skills_with_other_selections() {
+        let value = serde_json::json!({
+            "player_id": 1,
+            "name": "Infinity",
+            "level": 15,
+            "gender": "Male",
+            "rank": "Beginner Civilian",
+            "signup": "2004-11-16 00:00:00",
+            "nan": "NaN",
+            "status": {"description": "Okay", "details": "", "state": "Okay"},
+            "hunting": "12.34",
+            "racing": "100.00",
+        });
+
+        let skills = decode_skills(&value).unwrap();
+
+        assert_eq!(skills.len(), 2);
+        assert_eq!(skills["hunting"], 12.34);
+        assert_eq!(skills["racing"], 100.0);
+    }
 }
@@ -0,0 +1,456 @@
+use crate::{common::AttackResult, html};
+
+use super::Event;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TradeAction {
+    Initiated,
+    Accepted,
+    Declined,
+    Cancelled,
+    Expired,
+    Updated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompanyNotice {
+    Application,
+    Hired,
+    Left,
+    Fired,
+    Trained,
+    PositionChanged,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FactionNotice {
+    Application,
+    ApplicationDeclined,
+    Joined,
+    Left,
+    Kicked,
+    PositionChanged,
+    OrganisedCrime,
+    Other,
+}
+
+/// The structured content of an [Event], as classified by [EventKind::parse].
+///
+/// Users, factions and companies are only known when the event links to them, so anonymous
+/// attackers and the like are reported as `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventKind<'a> {
+    Attacked {
+        attacker_id: Option<i32>,
+        faction_id: Option<i32>,
+        result: AttackResult,
+    },
+    Mugged {
+        attacker_id: Option<i32>,
+        faction_id: Option<i32>,
+        amount: Option<i64>,
+    },
+    ReceivedMoney {
+        sender_id: Option<i32>,
+        faction_id: Option<i32>,
+        amount: i64,
+    },
+    ReceivedItems {
+        sender_id: Option<i32>,
+        faction_id: Option<i32>,
+        item: &'a str,
+        quantity: i32,
+    },
+    ItemSold {
+        buyer_id: Option<i32>,
+        item: &'a str,
+        quantity: i32,
+        amount: Option<i64>,
+    },
+    BountyClaimed {
+        claimer_id: Option<i32>,
+        target_id: Option<i32>,
+        amount: Option<i64>,
+    },
+    BountyPlaced {
+        placer_id: Option<i32>,
+        amount: Option<i64>,
+    },
+    Trade {
+        trader_id: Option<i32>,
+        trade_id: Option<i64>,
+        action: TradeAction,
+    },
+    Company {
+        user_id: Option<i32>,
+        company_id: Option<i32>,
+        notice: CompanyNotice,
+    },
+    Faction {
+        user_id: Option<i32>,
+        faction_id: Option<i32>,
+        notice: FactionNotice,
+    },
+    Unknown(&'a str),
+}
+
+impl<'a> EventKind<'a> {
+    pub fn parse(text: &'a str) -> Self {
+        // anything after the message is written by the sender and must not be used to classify
+        // the event
+        let body = text
+            .find(" with the message")
+            .map_or(text, |end| &text[..end]);
+        let markup = html::parse(body);
+
+        if markup.contains("bounty") {
+            if markup.contains("claimed") {
+                let (claimer_id, target_id) = if markup.contains("claimed by") {
+                    (markup.user_after("claimed by"), markup.user(0))
+                } else {
+                    (markup.user(0), markup.user_after("claimed"))
+                };
+                return Self::BountyClaimed {
+                    claimer_id,
+                    target_id,
+                    amount: markup.money(),
+                };
+            }
+            if markup.contains("placed") {
+                return Self::BountyPlaced {
+                    placer_id: markup.user_before("placed"),
+                    amount: markup.money(),
+                };
+            }
+        }
+
+        if markup.contains_any(&["mugged you", "mugged by", "you were mugged"]) {
+            return Self::Mugged {
+                attacker_id: markup.user(0),
+                faction_id: markup.faction(),
+                amount: markup.money(),
+            };
+        }
+
+        if markup.contains_any(&[
+            "attacked you",
+            "attacked by",
+            "hospitalized you",
+            "hospitalized by",
+        ]) {
+            let result = if markup.contains("hospitali") {
+                AttackResult::Hospitalized
+            } else if markup.contains("stalemate") {
+                AttackResult::Stalemate
+            } else if markup.contains("escape") {
+                AttackResult::Escape
+            } else if markup.contains("interrupted") {
+                AttackResult::Interrupted
+            } else if markup.contains_any(&["but lost", "and lost", "you won"]) {
+                AttackResult::Lost
+            } else {
+                AttackResult::Attacked
+            };
+            return Self::Attacked {
+                attacker_id: markup.user(0),
+                faction_id: markup.faction(),
+                result,
+            };
+        }
+
+        if markup.contains("trade") {
+            let action = if markup.contains_any(&["initiated", "started", "opened"]) {
+                Some(TradeAction::Initiated)
+            } else if markup.contains("accepted") {
+                Some(TradeAction::Accepted)
+            } else if markup.contains("declined") {
+                Some(TradeAction::Declined)
+            } else if markup.contains("cancel") {
+                Some(TradeAction::Cancelled)
+            } else if markup.contains("expired") {
+                Some(TradeAction::Expired)
+            } else if markup.contains_any(&["added", "removed", "changed", "modified"]) {
+                Some(TradeAction::Updated)
+            } else {
+                None
+            };
+            if let Some(action) = action {
+                return Self::Trade {
+                    trader_id: markup.user(0),
+                    trade_id: markup.trade(),
+                    action,
+                };
+            }
+        }
+
+        if markup.contains_any(&["you were sent", "sent you", "you received"]) {
+            let sender_id = markup.user(0);
+            let faction_id = markup.faction();
+
+            if let Some((quantity, item)) = markup.items() {
+                return Self::ReceivedItems {
+                    sender_id,
+                    faction_id,
+                    item,
+                    quantity,
+                };
+            }
+            if let Some(amount) = markup.money() {
+                return Self::ReceivedMoney {
+                    sender_id,
+                    faction_id,
+                    amount,
+                };
+            }
+            return Self::Unknown(text);
+        }
+
+        if markup.contains("bought") && markup.contains_any(&["bazaar", "item market"]) {
+            if let Some((quantity, item)) = markup.items() {
+                return Self::ItemSold {
+                    buyer_id: markup.user(0),
+                    item,
+                    quantity,
+                    amount: markup.money(),
+                };
+            }
+        }
+
+        if markup.contains_any(&["company", "job"]) || markup.company().is_some() {
+            let notice = if markup.contains("applied") {
+                CompanyNotice::Application
+            } else if markup.contains("fired") {
+                CompanyNotice::Fired
+            } else if markup.contains_any(&["hired", "accepted", "joined"]) {
+                CompanyNotice::Hired
+            } else if markup.contains_any(&["left", "quit"]) {
+                CompanyNotice::Left
+            } else if markup.contains("train") {
+                CompanyNotice::Trained
+            } else if markup.contains_any(&["promoted", "demoted", "position"]) {
+                CompanyNotice::PositionChanged
+            } else {
+                CompanyNotice::Other
+            };
+            return Self::Company {
+                user_id: markup.user(0),
+                company_id: markup.company(),
+                notice,
+            };
+        }
+
+        if markup.contains("faction") || markup.faction().is_some() {
+            let notice = if markup.contains_any(&["organized crime", "organised crime"]) {
+                FactionNotice::OrganisedCrime
+            } else if markup.contains("declined") {
+                FactionNotice::ApplicationDeclined
+            } else if markup.contains("kicked") {
+                FactionNotice::Kicked
+            } else if markup.contains_any(&["accepted", "joined"]) {
+                FactionNotice::Joined
+            } else if markup.contains_any(&["applied", "application"]) {
+                FactionNotice::Application
+            } else if markup.contains("left") {
+                FactionNotice::Left
+            } else if markup.contains_any(&["promoted", "demoted", "position"]) {
+                FactionNotice::PositionChanged
+            } else {
+                FactionNotice::Other
+            };
+            return Self::Faction {
+                user_id: markup.user(0),
+                faction_id: markup.faction(),
+                notice,
+            };
+        }
+
+        Self::Unknown(text)
+    }
+}
+
+impl<'a> Event<'a> {
+    pub fn kind(&self) -> EventKind<'a> {
+        EventKind::parse(self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attacked() {
+        assert_eq!(
+            EventKind::parse(
+                "<a href = \"http://www.torn.com/profiles.php?XID=2111649\">Pyrit</a> of <a \
+                 href = \"http://www.torn.com/factions.php?step=profile&ID=7049\">Fac</a> \
+                 attacked and hospitalized you [<a href = \
+                 \"http://www.torn.com/loader.php?sid=attackLog&ID=abc\">view</a>]"
+            ),
+            EventKind::Attacked {
+                attacker_id: Some(2111649),
+                faction_id: Some(7049),
+                result: AttackResult::Hospitalized,
+            }
+        );
+
+        assert_eq!(
+            EventKind::parse("Someone attacked you but lost [<a href = \"x\">view</a>]"),
+            EventKind::Attacked {
+                attacker_id: None,
+                faction_id: None,
+                result: AttackResult::Lost,
+            }
+        );
+    }
+
+    #[test]
+    fn mugged() {
+        assert_eq!(
+            EventKind::parse(
+                "<a href = http://www.torn.com/profiles.php?XID=1>Chedburn</a> mugged you and \
+                 stole $12,345"
+            ),
+            EventKind::Mugged {
+                attacker_id: Some(1),
+                faction_id: None,
+                amount: Some(12345),
+            }
+        );
+    }
+
+    #[test]
+    fn received() {
+        assert_eq!(
+            EventKind::parse(
+                "You were sent $1,000 from <a href = \
+                 http://www.torn.com/profiles.php?XID=1>Chedburn</a> with the message: 5x"
+            ),
+            EventKind::ReceivedMoney {
+                sender_id: Some(1),
+                faction_id: None,
+                amount: 1000,
+            }
+        );
+
+        assert_eq!(
+            EventKind::parse(
+                "You were sent $1 from <a href = \
+                 http://www.torn.com/profiles.php?XID=5>Bob</a> with the message: lol I \
+                 mugged you"
+            ),
+            EventKind::ReceivedMoney {
+                sender_id: Some(5),
+                faction_id: None,
+                amount: 1,
+            }
+        );
+
+        assert_eq!(
+            EventKind::parse(
+                "You were sent 10x Xanax from <a href = \
+                 http://www.torn.com/profiles.php?XID=1>Chedburn</a>."
+            ),
+            EventKind::ReceivedItems {
+                sender_id: Some(1),
+                faction_id: None,
+                item: "Xanax",
+                quantity: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn bounty() {
+        assert_eq!(
+            EventKind::parse(
+                "Your $100,000 bounty on <a href = \
+                 http://www.torn.com/profiles.php?XID=2>Target</a> was claimed by <a href = \
+                 http://www.torn.com/profiles.php?XID=1>Chedburn</a>"
+            ),
+            EventKind::BountyClaimed {
+                claimer_id: Some(1),
+                target_id: Some(2),
+                amount: Some(100_000),
+            }
+        );
+    }
+
+    #[test]
+    fn trade() {
+        assert_eq!(
+            EventKind::parse(
+                "<a href = http://www.torn.com/profiles.php?XID=1>Chedburn</a> has accepted the \
+                 trade. [<a href = http://www.torn.com/trade.php#step=view&ID=4321>view</a>]"
+            ),
+            EventKind::Trade {
+                trader_id: Some(1),
+                trade_id: Some(4321),
+                action: TradeAction::Accepted,
+            }
+        );
+    }
+
+    #[test]
+    fn faction() {
+        assert_eq!(
+            EventKind::parse(
+                "<a href = http://www.torn.com/profiles.php?XID=1>Chedburn</a> has kicked you \
+                 from <a href = http://www.torn.com/factions.php?step=profile&ID=7049>Fac</a>"
+            ),
+            EventKind::Faction {
+                user_id: Some(1),
+                faction_id: Some(7049),
+                notice: FactionNotice::Kicked,
+            }
+        );
+
+        assert_eq!(
+            EventKind::parse(
+                "<a href = http://www.torn.com/profiles.php?XID=1>Chedburn</a> has accepted your \
+                 application to join <a href = \
+                 http://www.torn.com/factions.php?step=profile&ID=7049>Fac</a>"
+            ),
+            EventKind::Faction {
+                user_id: Some(1),
+                faction_id: Some(7049),
+                notice: FactionNotice::Joined,
+            }
+        );
+
+        assert_eq!(
+            EventKind::parse(
+                "Your application to join <a href = \
+                 http://www.torn.com/factions.php?step=profile&ID=7049>Fac</a> has been accepted."
+            ),
+            EventKind::Faction {
+                user_id: None,
+                faction_id: Some(7049),
+                notice: FactionNotice::Joined,
+            }
+        );
+
+        assert_eq!(
+            EventKind::parse(
+                "<a href = http://www.torn.com/profiles.php?XID=2>Member</a> has applied to join \
+                 your faction"
+            ),
+            EventKind::Faction {
+                user_id: Some(2),
+                faction_id: None,
+                notice: FactionNotice::Application,
+            }
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        let text = "The weather in Torn is lovely today";
+        assert_eq!(EventKind::parse(text), EventKind::Unknown(text));
+    }
+}
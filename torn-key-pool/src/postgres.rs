@@ -357,7 +357,7 @@ where
                     return Ok(None);
                 }
 
-                keys.sort_unstable_by(|k1, k2| k1.uses.cmp(&k2.uses));
+                keys.sort_unstable_by_key(|k| k.uses);
 
                 let mut result = Vec::with_capacity(number as usize);
                 let (max, rest) = keys.split_last_mut().unwrap();
@@ -277,7 +277,7 @@ where
     C: ApiClient,
     S: KeyPoolStorage + Send + Sync + 'static,
 {
-    pub fn torn_api<I>(&self, selector: I) -> ApiProvider<C, KeyPoolExecutor<C, S>>
+    pub fn torn_api<I>(&self, selector: I) -> ApiProvider<'_, C, KeyPoolExecutor<'_, C, S>>
     where
         I: IntoSelector<S::Key, S::Domain>,
     {
@@ -297,7 +297,7 @@ pub trait WithStorage {
         &'a self,
         storage: &'a S,
         selector: I,
-    ) -> ApiProvider<Self, KeyPoolExecutor<Self, S>>
+    ) -> ApiProvider<'a, Self, KeyPoolExecutor<'a, Self, S>>
     where
         Self: ApiClient + Sized,
         S: KeyPoolStorage + Send + Sync + 'static,